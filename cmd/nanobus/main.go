@@ -9,6 +9,8 @@
 package main
 
 import (
+	"bufio"
+	"bytes"
 	"context"
 	"encoding/json"
 	"errors"
@@ -141,6 +143,12 @@ type invokeCmd struct {
 	Input string `arg:"" optional:"" type:"existingfile" help:"File to use as input JSON data"`
 	// Pretty is a flag to pretty print the JSON output.
 	Pretty bool `name:"pretty" default:"false" help:"Pretty print the JSON output"`
+	// Validate is a flag to only check the input against the operation's specification.
+	Validate bool `name:"validate" default:"false" help:"Validate the input against the operation's specification without invoking it"`
+	// NDJSON is a flag to read newline-delimited JSON inputs and invoke the operation once per line.
+	NDJSON bool `name:"ndjson" default:"false" help:"Read newline-delimited JSON inputs and invoke the operation once per line"`
+	// FailFast is a flag to stop a NDJSON batch on the first error.
+	FailFast bool `name:"fail-fast" default:"false" help:"Stop a NDJSON batch on the first error instead of reporting it inline"`
 	// Turns on debug logging.
 	Debug bool `name:"debug" help:"Turns on debug logging"`
 }
@@ -149,22 +157,22 @@ func (c *invokeCmd) Run() error {
 	ctx, cancel := context.WithCancel(context.Background())
 	defer cancel()
 
-	inputFile := os.Stdin
-	if c.Input != "" {
-		f, err := os.Open(c.Input)
-		if err != nil {
-			return err
-		}
-		defer f.Close()
-	}
-	inputBytes, err := io.ReadAll(inputFile)
+	inputFile, err := c.openInput()
 	if err != nil {
-		return fmt.Errorf("could not read stdin: %w", err)
+		return err
 	}
+	defer inputFile.Close()
 
 	var input map[string]interface{}
-	if err := json.Unmarshal(inputBytes, &input); err != nil {
-		return fmt.Errorf("could not parse stdin: %w", err)
+	if !c.NDJSON {
+		inputBytes, err := io.ReadAll(inputFile)
+		if err != nil {
+			return fmt.Errorf("could not read stdin: %w", err)
+		}
+
+		if err := json.Unmarshal(inputBytes, &input); err != nil {
+			return fmt.Errorf("could not parse stdin: %w", err)
+		}
 	}
 
 	var h handler.Handler
@@ -195,6 +203,26 @@ func (c *invokeCmd) Run() error {
 	}
 	defer e.Shutdown()
 
+	if c.NDJSON {
+		return invokeNDJSON(inputFile, os.Stdout, c.FailFast, func(input map[string]any) (any, error) {
+			if c.Validate {
+				if err := e.Validate(h, input); err != nil {
+					return nil, err
+				}
+				return map[string]any{"valid": true}, nil
+			}
+			return e.InvokeUnsafe(h, input)
+		})
+	}
+
+	if c.Validate {
+		if err := e.Validate(h, input); err != nil {
+			return fmt.Errorf("invalid input for %s: %w", c.Operation, err)
+		}
+		fmt.Println("Input is valid")
+		return nil
+	}
+
 	var result any
 	result, err = e.InvokeUnsafe(h, input)
 	if err != nil {
@@ -219,6 +247,64 @@ func (c *invokeCmd) Run() error {
 	return nil
 }
 
+// openInput returns the input file, or stdin when no file was given.
+func (c *invokeCmd) openInput() (io.ReadCloser, error) {
+	if c.Input == "" {
+		return io.NopCloser(os.Stdin), nil
+	}
+	return os.Open(c.Input)
+}
+
+// ndjsonError is written in place of a result when a NDJSON input fails.
+type ndjsonError struct {
+	Line  int    `json:"line"`
+	Error string `json:"error"`
+}
+
+// invokeNDJSON reads newline-delimited JSON inputs from r, calls invoke
+// once per line and writes one JSON document per input to w, preserving
+// input order. Failures are written inline unless failFast is set, in
+// which case the first failure stops the batch.
+func invokeNDJSON(r io.Reader, w io.Writer, failFast bool, invoke func(input map[string]any) (any, error)) error {
+	reader := bufio.NewReader(r)
+	for lineNum := 1; ; lineNum++ {
+		line, readErr := reader.ReadBytes('\n')
+		if readErr != nil && readErr != io.EOF {
+			return fmt.Errorf("could not read stdin: %w", readErr)
+		}
+
+		if len(bytes.TrimSpace(line)) > 0 {
+			var result any
+			var input map[string]any
+			err := json.Unmarshal(line, &input)
+			if err != nil {
+				err = fmt.Errorf("could not parse input: %w", err)
+			} else {
+				result, err = invoke(input)
+			}
+			if err != nil {
+				if failFast {
+					return fmt.Errorf("line %d: %w", lineNum, err)
+				}
+				result = ndjsonError{
+					Line:  lineNum,
+					Error: err.Error(),
+				}
+			}
+
+			jsonBytes, err := json.Marshal(result)
+			if err != nil {
+				return fmt.Errorf("error converting output to JSON: %w", err)
+			}
+			fmt.Fprintln(w, string(jsonBytes))
+		}
+
+		if readErr == io.EOF {
+			return nil
+		}
+	}
+}
+
 type pushCmd struct {
 	// BusFile is the application configuration (not an OCI image reference).
 	BusFile string `type:"existingFile" arg:"" default:"bus.yaml" help:"The NanoBus application configuration"`
@@ -300,10 +386,12 @@ func (c *pushCmd) Run() error {
 type pullCmd struct {
 	// Reference is the full OCI image reference to pull.
 	Reference string `arg:"" help:"The OCI image reference to pull"`
+	// Mirrors are registry hosts to try when the registry is unavailable.
+	Mirrors []string `name:"mirror" help:"Registry mirror to pull from when the registry is unavailable (repeatable)"`
 }
 
 func (c *pullCmd) Run() error {
-	if _, err := oci.Pull(c.Reference, "."); err != nil {
+	if _, err := oci.Pull(c.Reference, ".", c.Mirrors...); err != nil {
 		fmt.Printf("Error pulling image: %s\n", err)
 		return err
 	}
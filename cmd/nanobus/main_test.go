@@ -0,0 +1,90 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package main
+
+import (
+	"bytes"
+	"errors"
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+func TestInvokeNDJSON(t *testing.T) {
+	invoke := func(input map[string]any) (any, error) {
+		if input["name"] == "fail" {
+			return nil, errors.New("boom")
+		}
+		return map[string]any{"greeting": "Hello, " + input["name"].(string)}, nil
+	}
+
+	tests := map[string]struct {
+		input    string
+		failFast bool
+		expected string
+		err      string
+	}{
+		"batch": {
+			input: `{"name":"a"}` + "\n" + `{"name":"b"}` + "\n\n" + `{"name":"c"}`,
+			expected: `{"greeting":"Hello, a"}` + "\n" +
+				`{"greeting":"Hello, b"}` + "\n" +
+				`{"greeting":"Hello, c"}` + "\n",
+		},
+		"inline errors": {
+			input: `{"name":"a"}` + "\n" + `{"name":"fail"}` + "\n" + `not json` + "\n" + `{"name":"c"}` + "\n",
+			expected: `{"greeting":"Hello, a"}` + "\n" +
+				`{"line":2,"error":"boom"}` + "\n" +
+				`{"line":3,"error":"could not parse input: invalid character 'o' in literal null (expecting 'u')"}` + "\n" +
+				`{"greeting":"Hello, c"}` + "\n",
+		},
+		"fail fast": {
+			input:    `{"name":"a"}` + "\n" + `{"name":"fail"}` + "\n" + `{"name":"c"}` + "\n",
+			failFast: true,
+			expected: `{"greeting":"Hello, a"}` + "\n",
+			err:      "line 2: boom",
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			var out bytes.Buffer
+			err := invokeNDJSON(strings.NewReader(tt.input), &out, tt.failFast, invoke)
+			if tt.err != "" {
+				assert.EqualError(t, err, tt.err)
+			} else {
+				require.NoError(t, err)
+			}
+			assert.Equal(t, tt.expected, out.String())
+		})
+	}
+}
+
+func TestInvokeInputFile(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "input.ndjson")
+	require.NoError(t, os.WriteFile(path, []byte(`{"name":"a"}`+"\n"+`{"name":"b"}`+"\n"), 0600))
+
+	c := invokeCmd{Input: path}
+	r, err := c.openInput()
+	require.NoError(t, err)
+	defer r.Close()
+
+	var out bytes.Buffer
+	require.NoError(t, invokeNDJSON(r, &out, false, func(input map[string]any) (any, error) {
+		return input["name"], nil
+	}))
+	assert.Equal(t, `"a"`+"\n"+`"b"`+"\n", out.String())
+
+	c = invokeCmd{Input: filepath.Join(t.TempDir(), "missing.json")}
+	_, err = c.openInput()
+	assert.ErrorIs(t, err, os.ErrNotExist)
+}
@@ -30,7 +30,7 @@ export class ComponentsVisitor extends BaseVisitor {
       .write(`// Code generated by NanoBus codegen utilities. DO NOT EDIT.
 
 // deno-lint-ignore-file no-explicit-any no-unused-vars ban-unused-ignore
-import { CodecRef, Component, DataExpr, Handler, Entity, ResourceRef, Step, ValueExpr } from "../nanobus.ts";\n\n`);
+import { CodecRef, Component, DataExpr, Duration, Handler, Entity, ResourceRef, Step, ValueExpr } from "../nanobus.ts";\n\n`);
   }
 
   visitAlias(context: Context): void {
@@ -42,6 +42,7 @@ import { CodecRef, Component, DataExpr, Handler, Entity, ResourceRef, Step, Valu
         "Component",
         "ValueExpr",
         "DataExpr",
+        "Duration",
         "Handler",
         "Entity",
         "ResourceRef",
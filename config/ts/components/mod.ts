@@ -15,9 +15,11 @@ export * from "./blob_actions.ts";
 export * from "./postgres_actions.ts";
 export * from "./transport_cors.ts";
 export * from "./transport_dapr.ts";
+export * from "./transport_health.ts";
 export * from "./transport_jwt.ts";
 export * from "./transport_oauth2.ts";
 export * from "./transport_paseto.ts";
+export * from "./transport_ratelimit.ts";
 export * from "./transport_rest.ts";
 export * from "./transport_router.ts";
 export * from "./transport_server.ts";
@@ -0,0 +1,26 @@
+// Code generated by NanoBus codegen utilities. DO NOT EDIT.
+
+// deno-lint-ignore-file no-explicit-any no-unused-vars ban-unused-ignore
+import {
+  CodecRef,
+  Component,
+  DataExpr,
+  Duration,
+  Handler,
+  Entity,
+  ResourceRef,
+  Step,
+  ValueExpr
+} from "../nanobus.ts";
+
+export interface HealthV1Config {
+  // The path of the health check endpoint. Defaults to `/healthz`.
+  path?: string;
+}
+
+export function HealthV1(config: HealthV1Config): Component<HealthV1Config> {
+  return {
+    uses: "nanobus.transport.http.health/v1",
+    with: config
+  };
+}
@@ -5,6 +5,7 @@ import {
   CodecRef,
   Component,
   DataExpr,
+  Duration,
   Handler,
   Entity,
   ResourceRef,
@@ -0,0 +1,38 @@
+// Code generated by NanoBus codegen utilities. DO NOT EDIT.
+
+// deno-lint-ignore-file no-explicit-any no-unused-vars ban-unused-ignore
+import {
+  CodecRef,
+  Component,
+  DataExpr,
+  Duration,
+  Handler,
+  Entity,
+  ResourceRef,
+  Step,
+  ValueExpr
+} from "../nanobus.ts";
+
+export interface RateLimitV0Config {
+  // The rate limits to apply. When several paths match a request, the longest one
+  // is used.
+  limits: RateLimit[];
+}
+
+export function RateLimitV0(
+  config: RateLimitV0Config
+): Component<RateLimitV0Config> {
+  return {
+    uses: "nanobus.transport.http.ratelimit/v0",
+    with: config
+  };
+}
+
+export interface RateLimit {
+  // The path prefix the limit applies to (e.g. `/greeter/`).
+  path: string;
+  // The number of requests allowed per second.
+  perSecond: number;
+  // The number of requests allowed in a single burst. Defaults to `perSecond`.
+  burst?: number;
+}
@@ -5,6 +5,7 @@ import {
   CodecRef,
   Component,
   DataExpr,
+  Duration,
   Handler,
   Entity,
   ResourceRef,
@@ -13,12 +14,25 @@ import {
 } from "../nanobus.ts";
 
 export interface HttpServerV1Config {
-  // The listening address of the server.
+  // The listening address of the server. Use `unix:/path/to.sock` to listen on a
+  // Unix domain socket.
   address: string;
   // Array of [HTTP Middleware](/category/http-middleware) component configurations.
   middleware?: Component<any>[];
   // Array of [HTTP Router](/category/http-routers) component configurations.
   routers?: Component<any>[];
+  // The maximum time to wait for in-flight requests to complete when the server
+  // shuts down. Connections still active afterwards are closed. Defaults to 10s.
+  drainTimeout?: Duration;
+  // The interval between TCP keep-alive probes on accepted connections. Probes keep
+  // idle long-lived connections from being dropped by intermediary proxies. Defaults
+  // to 15s. A negative value disables keep-alive probes.
+  keepAlive?: Duration;
+  // The maximum time to keep an idle keep-alive connection open while waiting for
+  // the next request. Defaults to no timeout.
+  idleTimeout?: Duration;
+  // Serves HTTPS using the configured certificate instead of plain HTTP.
+  tls?: TLS;
 }
 
 export function HttpServerV1(
@@ -29,3 +43,13 @@ export function HttpServerV1(
     with: config
   };
 }
+
+export interface TLS {
+  // Path to the PEM encoded server certificate.
+  certFile: string;
+  // Path to the PEM encoded private key of the server certificate.
+  keyFile: string;
+  // Path to a PEM encoded CA bundle. When set, clients must present a
+  // certificate signed by one of these CAs (mutual TLS).
+  clientCAFile?: string;
+}
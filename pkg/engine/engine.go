@@ -113,9 +113,11 @@ import (
 	// TRANSPORT - HTTP MIDDLEWARE
 	"github.com/nanobus/nanobus/pkg/transport/http/middleware"
 	middleware_cors "github.com/nanobus/nanobus/pkg/transport/http/middleware/cors"
+	middleware_ratelimit "github.com/nanobus/nanobus/pkg/transport/http/middleware/ratelimit"
 
 	// TRANSPORT - HTTP ROUTERS
 	"github.com/nanobus/nanobus/pkg/transport/http/router"
+	router_health "github.com/nanobus/nanobus/pkg/transport/http/router/health"
 	router_oauth2 "github.com/nanobus/nanobus/pkg/transport/http/router/oauth2"
 	router_rest "github.com/nanobus/nanobus/pkg/transport/http/router/rest"
 	router_router "github.com/nanobus/nanobus/pkg/transport/http/router/router"
@@ -161,6 +163,7 @@ type Engine struct {
 	resolver       resolve.DependencyResolver
 	resolveAs      resolve.ResolveAs
 	namespaces     spec.Namespaces
+	interfaces     spec.Interfaces
 	m              *mesh.Mesh
 	allNamespaces  runtime.Namespaces
 	codec          channel.Codec
@@ -339,6 +342,7 @@ func Start(ctx context.Context, info *Info) (*Engine, error) {
 	// Router registration
 	routerRegistry := router.Registry{}
 	routerRegistry.Register(
+		router_health.HealthV1,
 		router_oauth2.OAuth2V1,
 		router_rest.RestV1,
 		router_router.RouterV1,
@@ -348,6 +352,7 @@ func Start(ctx context.Context, info *Info) (*Engine, error) {
 	middlewareRegistry := middleware.Registry{}
 	middlewareRegistry.Register(
 		middleware_cors.CorsV0,
+		middleware_ratelimit.RateLimitV0,
 	)
 
 	// Compute registration
@@ -625,6 +630,7 @@ func Start(ctx context.Context, info *Info) (*Engine, error) {
 	}
 
 	interfaces := namespaces.ToInterfaces()
+	e.interfaces = interfaces
 
 	// Check for unsatified imports
 	ops := m.Unsatisfied()
@@ -767,7 +773,11 @@ func Start(ctx context.Context, info *Info) (*Engine, error) {
 
 			future := m.RequestResponse(ctx, h, p)
 			if future == nil {
-				return nil, errorz.New(errorz.Unimplemented, fmt.Sprintf("%s is not implemented", h.String()))
+				message := fmt.Sprintf("%s is not implemented", h.String())
+				if suggestion, ok := suggestOperation(h, interfaces, allNamespaces); ok {
+					message += fmt.Sprintf("; did you mean %s?", suggestion)
+				}
+				return nil, errorz.New(errorz.Unimplemented, message)
 			}
 			result, err := future.Block()
 			if err != nil {
@@ -793,41 +803,8 @@ func Start(ctx context.Context, info *Info) (*Engine, error) {
 			log.Info("Warning: no transports configured")
 		}
 
-		var g run.Group
-		if len(info.Process) > 0 {
-			log.Info("Executing process", "cmd", strings.Join(info.Process, " "))
-			command := info.Process[0]
-			args := info.Process[1:]
-			cmd := exec.CommandContext(ctx, command, args...)
-			g.Add(func() error {
-				appEnv := []string{}
-				env := []string{}
-				env = append(env, os.Environ()...)
-				env = append(env, appEnv...)
-				cmd.Env = env
-				cmd.Stdin = os.Stdin
-				cmd.Stdout = os.Stdout
-				cmd.Stderr = os.Stderr
-				return cmd.Run()
-			}, func(error) {
-				// TODO: send term sig instead
-				if cmd.Process != nil {
-					if err := cmd.Process.Kill(); err != nil {
-						logger.Error("Error killing process", "error", err)
-					}
-				}
-			})
-		}
-		{
-			g.Add(func() error {
-				return m.WaitUntilShutdown()
-			}, func(error) {
-				m.Close()
-			})
-		}
-
+		transports := make([]transport.Transport, 0, len(busConfig.Transports))
 		for name, comp := range busConfig.Transports {
-			name := name // Make copy
 			loader, ok := transportRegistry[comp.Uses]
 			if !ok {
 				log.Error(nil, "unknown transport", "type", comp.Uses)
@@ -839,19 +816,10 @@ func Start(ctx context.Context, info *Info) (*Engine, error) {
 				log.Error(err, "could not load transport", "type", comp.Uses)
 				return nil, err
 			}
-
-			g.Add(func() error {
-				return t.Listen()
-			}, func(error) {
-				t.Close()
-			})
+			transports = append(transports, t)
 		}
 
-		{
-			g.Add(run.SignalHandler(ctx, syscall.SIGINT, syscall.SIGTERM))
-		}
-
-		err = g.Run()
+		err = serve(ctx, m, info.Process, transports)
 		e.Shutdown()
 		if err != nil {
 			if _, isSignal := err.(run.SignalError); !isSignal {
@@ -863,6 +831,63 @@ func Start(ctx context.Context, info *Info) (*Engine, error) {
 	return &e, nil
 }
 
+// serve runs the transports, the mesh and the application process until
+// one of them stops or a shutdown signal is received. Interrupts run in
+// the order actors are added, so transports are added first: they drain
+// in-flight requests while the mesh and the application can still serve
+// them. All transports drain concurrently.
+func serve(ctx context.Context, m *mesh.Mesh, process []string, transports []transport.Transport) error {
+	var g run.Group
+
+	if len(transports) > 0 {
+		listening := make(chan error, len(transports))
+		g.Add(func() error {
+			for _, t := range transports {
+				t := t // Make copy
+				go func() { listening <- t.Listen() }()
+			}
+			return <-listening
+		}, func(error) {
+			if err := transport.CloseAll(transports...); err != nil {
+				logger.Error("Error closing transports", "error", err)
+			}
+		})
+	}
+
+	if len(process) > 0 {
+		logger.Info("Executing process", "cmd", strings.Join(process, " "))
+		cmd := exec.CommandContext(ctx, process[0], process[1:]...)
+		g.Add(func() error {
+			appEnv := []string{}
+			env := []string{}
+			env = append(env, os.Environ()...)
+			env = append(env, appEnv...)
+			cmd.Env = env
+			cmd.Stdin = os.Stdin
+			cmd.Stdout = os.Stdout
+			cmd.Stderr = os.Stderr
+			return cmd.Run()
+		}, func(error) {
+			// TODO: send term sig instead
+			if cmd.Process != nil {
+				if err := cmd.Process.Kill(); err != nil {
+					logger.Error("Error killing process", "error", err)
+				}
+			}
+		})
+	}
+
+	g.Add(func() error {
+		return m.WaitUntilShutdown()
+	}, func(error) {
+		m.Close()
+	})
+
+	g.Add(run.SignalHandler(ctx, syscall.SIGINT, syscall.SIGTERM))
+
+	return g.Run()
+}
+
 func (e *Engine) InvokeUnsafe(handler handler.Handler, input any) (any, error) {
 	return e.transportInvoker(e.ctx, handler, "", input, transport.BypassAuthorization)
 }
@@ -871,6 +896,24 @@ func (e *Engine) Invoke(handler handler.Handler, input any) (any, error) {
 	return e.transportInvoker(e.ctx, handler, "", input, transport.PerformAuthorization)
 }
 
+// Validate checks input against the operation's specification, applying
+// the same coercion as an invocation, without running the operation.
+// Operations that are neither specified nor implemented are reported as
+// not found.
+func (e *Engine) Validate(handler handler.Handler, input any) error {
+	_, specified := e.interfaces.Operation(handler)
+	_, implemented := e.allNamespaces[handler.Interface][handler.Operation]
+	if !specified && !implemented {
+		message := fmt.Sprintf("%s does not exist", handler.String())
+		if suggestion, ok := suggestOperation(handler, e.interfaces, e.allNamespaces); ok {
+			message += fmt.Sprintf("; did you mean %s?", suggestion)
+		}
+		return errorz.New(errorz.NotFound, message)
+	}
+
+	return coalesceInput(e.interfaces, handler, input)
+}
+
 func (e *Engine) Shutdown() {
 	e.log.Info("Shutting down")
 	for name, r := range e.resources {
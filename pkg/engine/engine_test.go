@@ -10,11 +10,19 @@ package engine
 
 import (
 	"context"
+	"errors"
 	"fmt"
+	"sync/atomic"
 	"testing"
+	"time"
 
+	"github.com/nanobus/nanobus/pkg/errorz"
 	"github.com/nanobus/nanobus/pkg/handler"
+	"github.com/nanobus/nanobus/pkg/mesh"
+	"github.com/nanobus/nanobus/pkg/transport"
 	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/otel/trace"
 	"go.uber.org/zap/zapcore"
 )
 
@@ -42,3 +50,156 @@ func TestInvoke(t *testing.T) {
 	assert.Equal(t, "Hello, World", response)
 	fmt.Println(response)
 }
+
+func TestInvokeSuggestion(t *testing.T) {
+	ctx := context.Background()
+	info := Info{
+		Mode:          ModeInvoke,
+		LogLevel:      zapcore.ErrorLevel,
+		Target:        "test-data/greeter.yaml",
+		DeveloperMode: false,
+	}
+	engine, err := Start(ctx, &info)
+	assert.NoError(t, err)
+
+	_, err = engine.InvokeUnsafe(handler.Handler{Interface: "Greeter", Operation: "SayHelo"}, map[string]interface{}{})
+	assert.EqualError(t, err, "Greeter::SayHelo is not implemented; did you mean Greeter::SayHello?")
+
+	_, err = engine.InvokeUnsafe(handler.Handler{Interface: "Greeter", Operation: "Goodbye"}, map[string]interface{}{})
+	assert.EqualError(t, err, "Greeter::Goodbye is not implemented")
+}
+
+func TestValidate(t *testing.T) {
+	ctx := context.Background()
+	info := Info{
+		Mode:          ModeInvoke,
+		LogLevel:      zapcore.ErrorLevel,
+		Target:        "test-data/greeter-spec.yaml",
+		DeveloperMode: false,
+	}
+	engine, err := Start(ctx, &info)
+	require.NoError(t, err)
+
+	sayHello := handler.Handler{Interface: "greeter.v1.Greeter", Operation: "sayHello"}
+	tests := map[string]struct {
+		handler handler.Handler
+		input   map[string]interface{}
+		code    errorz.ErrCode
+		badType bool
+		message string
+	}{
+		"valid": {
+			handler: sayHello,
+			input:   map[string]interface{}{"name": "World", "times": 2},
+		},
+		"mistyped field": {
+			handler: sayHello,
+			input:   map[string]interface{}{"name": "World", "times": "twice"},
+			badType: true,
+		},
+		"missing field": {
+			handler: sayHello,
+			input:   map[string]interface{}{"times": 2},
+			code:    errorz.InvalidArgument,
+		},
+		"unknown operation": {
+			handler: handler.Handler{Interface: "greeter.v1.Greeter", Operation: "sayHelo"},
+			input:   map[string]interface{}{"name": "World", "times": 2},
+			code:    errorz.NotFound,
+			message: "greeter.v1.Greeter::sayHelo does not exist; did you mean greeter.v1.Greeter::sayHello?",
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			err := engine.Validate(tt.handler, tt.input)
+			var errz *errorz.Error
+			switch {
+			case tt.badType:
+				assert.True(t, errors.Is(err, transport.ErrBadInput), "%v", err)
+			case tt.code != 0:
+				require.True(t, errors.As(err, &errz), "%v", err)
+				assert.Equal(t, tt.code, errz.Code)
+				if tt.message != "" {
+					assert.Equal(t, tt.message, errz.Message)
+				}
+			default:
+				assert.NoError(t, err)
+			}
+		})
+	}
+}
+
+// slowTransport has one request in flight that only reaches the mesh
+// after shutdown has started. Close drains it like the real transports.
+type slowTransport struct {
+	meshClosed *atomic.Bool
+	started    chan struct{}
+	drained    chan struct{}
+	closed     chan struct{}
+	err        error
+}
+
+func (t *slowTransport) Listen() error {
+	go func() {
+		defer close(t.drained)
+		close(t.started)
+		time.Sleep(100 * time.Millisecond)
+		if t.meshClosed.Load() {
+			t.err = errors.New("mesh closed before the request completed")
+		}
+	}()
+	<-t.closed
+	return nil
+}
+
+func (t *slowTransport) Close() error {
+	<-t.drained
+	close(t.closed)
+	return nil
+}
+
+func TestServeDrainsBeforeClosingMesh(t *testing.T) {
+	m := mesh.New(trace.NewNoopTracerProvider().Tracer(""))
+	var meshClosed atomic.Bool
+	go func() {
+		m.WaitUntilShutdown()
+		meshClosed.Store(true)
+	}()
+
+	slow := &slowTransport{
+		meshClosed: &meshClosed,
+		started:    make(chan struct{}),
+		drained:    make(chan struct{}),
+		closed:     make(chan struct{}),
+	}
+
+	ctx, cancel := context.WithCancel(context.Background())
+	done := make(chan error, 1)
+	go func() { done <- serve(ctx, m, nil, []transport.Transport{slow}) }()
+
+	<-slow.started
+	cancel()
+
+	assert.ErrorIs(t, <-done, context.Canceled)
+	assert.NoError(t, slow.err)
+	assert.Eventually(t, meshClosed.Load, time.Second, 10*time.Millisecond)
+}
+
+func TestLevenshtein(t *testing.T) {
+	tests := []struct {
+		a, b     string
+		expected int
+	}{
+		{"", "", 0},
+		{"", "abc", 3},
+		{"add-item", "add-item", 0},
+		{"add-itm", "add-item", 1},
+		{"kitten", "sitting", 3},
+		{"héllo", "hello", 1},
+	}
+
+	for _, tt := range tests {
+		assert.Equal(t, tt.expected, levenshtein(tt.a, tt.b), "%q -> %q", tt.a, tt.b)
+	}
+}
@@ -0,0 +1,84 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package engine
+
+import (
+	"github.com/nanobus/nanobus/pkg/handler"
+	"github.com/nanobus/nanobus/pkg/runtime"
+	"github.com/nanobus/nanobus/pkg/spec"
+)
+
+// maxSuggestionDistance is the largest edit distance for which an
+// operation is suggested. Larger distances produce unrelated names.
+const maxSuggestionDistance = 2
+
+// suggestOperation returns the known operation whose name is closest to
+// h, if it is within maxSuggestionDistance edits.
+func suggestOperation(h handler.Handler, interfaces spec.Interfaces, namespaces runtime.Namespaces) (string, bool) {
+	target := h.String()
+	best, bestDistance := "", maxSuggestionDistance+1
+
+	consider := func(iface, oper string) {
+		candidate := handler.Handler{Interface: iface, Operation: oper}
+		name := candidate.String()
+		distance := levenshtein(target, name)
+		// Ties are broken by name so the suggestion is stable.
+		if distance < bestDistance || (distance == bestDistance && name < best) {
+			best, bestDistance = name, distance
+		}
+	}
+
+	for iface, opers := range interfaces {
+		for oper := range opers {
+			consider(iface, oper)
+		}
+	}
+	for iface, funcs := range namespaces {
+		for oper := range funcs {
+			consider(iface, oper)
+		}
+	}
+
+	return best, bestDistance <= maxSuggestionDistance
+}
+
+// levenshtein returns the number of single character insertions,
+// deletions and substitutions needed to turn a into b.
+func levenshtein(a, b string) int {
+	ra, rb := []rune(a), []rune(b)
+	prev := make([]int, len(rb)+1)
+	curr := make([]int, len(rb)+1)
+	for j := range prev {
+		prev[j] = j
+	}
+
+	for i := 1; i <= len(ra); i++ {
+		curr[0] = i
+		for j := 1; j <= len(rb); j++ {
+			cost := 1
+			if ra[i-1] == rb[j-1] {
+				cost = 0
+			}
+			curr[j] = minOf(prev[j]+1, curr[j-1]+1, prev[j-1]+cost)
+		}
+		prev, curr = curr, prev
+	}
+
+	return prev[len(rb)]
+}
+
+func minOf(values ...int) int {
+	m := values[0]
+	for _, v := range values[1:] {
+		if v < m {
+			m = v
+		}
+	}
+	return m
+}
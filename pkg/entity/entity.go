@@ -23,10 +23,20 @@ func (e *Entity) String() string {
 	return e.Namespace + "::" + e.Type
 }
 
+// FromString parses an entity in the form `namespace::Type`. Both parts
+// are required and may not contain surrounding whitespace.
 func (e *Entity) FromString(typeName string) error {
 	parts := strings.Split(typeName, "::")
 	if len(parts) != 2 {
-		return fmt.Errorf("invalid entity format %q", typeName)
+		return fmt.Errorf("invalid entity format %q: expected namespace::Type", typeName)
+	}
+	for i, name := range []string{"namespace", "type"} {
+		if parts[i] == "" {
+			return fmt.Errorf("invalid entity format %q: missing %s", typeName, name)
+		}
+		if strings.TrimSpace(parts[i]) != parts[i] {
+			return fmt.Errorf("invalid entity format %q: %s has surrounding whitespace", typeName, name)
+		}
 	}
 	*e = Entity{
 		Namespace: parts[0],
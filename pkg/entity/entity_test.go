@@ -27,3 +27,54 @@ func TestContext(t *testing.T) {
 	require.NoError(t, err)
 	assert.Equal(t, expected, actual)
 }
+
+func TestFromString(t *testing.T) {
+	tests := map[string]struct {
+		input    string
+		expected entity.Entity
+		err      string
+	}{
+		"valid": {
+			input:    "test.v1::Blog",
+			expected: entity.Entity{Namespace: "test.v1", Type: "Blog"},
+		},
+		"empty": {
+			input: "",
+			err:   `invalid entity format "": expected namespace::Type`,
+		},
+		"no separator": {
+			input: "test.v1.Blog",
+			err:   `invalid entity format "test.v1.Blog": expected namespace::Type`,
+		},
+		"too many parts": {
+			input: "test::v1::Blog",
+			err:   `invalid entity format "test::v1::Blog": expected namespace::Type`,
+		},
+		"missing namespace": {
+			input: "::Blog",
+			err:   `invalid entity format "::Blog": missing namespace`,
+		},
+		"missing type": {
+			input: "test.v1::",
+			err:   `invalid entity format "test.v1::": missing type`,
+		},
+		"whitespace": {
+			input: "test.v1:: Blog",
+			err:   `invalid entity format "test.v1:: Blog": type has surrounding whitespace`,
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			var actual entity.Entity
+			err := actual.FromString(tt.input)
+			if tt.err != "" {
+				assert.EqualError(t, err, tt.err)
+				return
+			}
+			require.NoError(t, err)
+			assert.Equal(t, tt.expected, actual)
+			assert.Equal(t, tt.input, actual.String())
+		})
+	}
+}
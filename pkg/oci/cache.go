@@ -0,0 +1,77 @@
+package oci
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"path/filepath"
+
+	"oras.land/oras-go/v2"
+	ocilayout "oras.land/oras-go/v2/content/oci"
+	"oras.land/oras-go/v2/registry"
+)
+
+// cacheDir returns the directory of the local image cache. It defaults
+// to `nanobus/oci` under the user's cache directory and can be changed
+// with the `OCI_CACHE_DIR` environment variable.
+func cacheDir() (string, error) {
+	if dir := os.Getenv("OCI_CACHE_DIR"); dir != "" {
+		return dir, nil
+	}
+
+	userCacheDir, err := os.UserCacheDir()
+	if err != nil {
+		return "", err
+	}
+	return filepath.Join(userCacheDir, "nanobus", "oci"), nil
+}
+
+// openCache opens the content-addressed OCI layout used to cache pulled
+// images. The layout keeps its index in memory and rewrites index.json
+// when an image is tagged, so the cache stays locked against other
+// processes until the returned release function is called.
+func openCache() (*ocilayout.Store, func(), error) {
+	dir, err := cacheDir()
+	if err != nil {
+		return nil, nil, err
+	}
+	if err := os.MkdirAll(filepath.Dir(dir), 0755); err != nil {
+		return nil, nil, err
+	}
+
+	release, err := lockFile(dir + ".lock")
+	if err != nil {
+		return nil, nil, fmt.Errorf("could not lock the image cache: %w", err)
+	}
+	store, err := ocilayout.New(dir)
+	if err != nil {
+		release()
+		return nil, nil, err
+	}
+
+	return store, release, nil
+}
+
+// cacheImage copies the image at ref from src into the cache with opts
+// and tags it as cacheRef. Blobs that are already cached are not
+// downloaded again. Digest references that are already cached skip the
+// registry entirely, and a tag that was cached before is used if the
+// registry is unavailable.
+func cacheImage(ctx context.Context, src oras.ReadOnlyTarget, ref registry.Reference, cache oras.Target, cacheRef string, opts oras.CopyOptions) error {
+	if _, err := ref.Digest(); err == nil {
+		if _, err := cache.Resolve(ctx, cacheRef); err == nil {
+			fmt.Println("Using cached", cacheRef)
+			return nil
+		}
+	}
+
+	_, err := oras.Copy(ctx, src, ref.Reference, cache, cacheRef, opts)
+	if err != nil && isUnavailable(err) {
+		if _, rerr := cache.Resolve(ctx, cacheRef); rerr == nil {
+			fmt.Println("Registry unavailable, using cached", cacheRef)
+			return nil
+		}
+	}
+
+	return err
+}
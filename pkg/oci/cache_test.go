@@ -0,0 +1,183 @@
+package oci
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"io"
+	"net/http"
+	"os"
+	"path/filepath"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/opencontainers/go-digest"
+	specs "github.com/opencontainers/image-spec/specs-go"
+	ocispec "github.com/opencontainers/image-spec/specs-go/v1"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"oras.land/oras-go/v2"
+	"oras.land/oras-go/v2/content"
+	"oras.land/oras-go/v2/content/memory"
+	"oras.land/oras-go/v2/registry"
+	"oras.land/oras-go/v2/registry/remote/errcode"
+)
+
+func TestCacheDir(t *testing.T) {
+	t.Setenv("OCI_CACHE_DIR", "/tmp/nanobus-cache")
+	dir, err := cacheDir()
+	require.NoError(t, err)
+	assert.Equal(t, "/tmp/nanobus-cache", dir)
+
+	t.Setenv("OCI_CACHE_DIR", "")
+	userCacheDir, err := os.UserCacheDir()
+	require.NoError(t, err)
+	dir, err = cacheDir()
+	require.NoError(t, err)
+	assert.Equal(t, filepath.Join(userCacheDir, "nanobus", "oci"), dir)
+}
+
+// testRegistry is a source that counts requests and can be made to fail
+// like an unavailable registry.
+type testRegistry struct {
+	oras.ReadOnlyTarget
+	calls int
+	err   error
+}
+
+func (r *testRegistry) Resolve(ctx context.Context, reference string) (ocispec.Descriptor, error) {
+	r.calls++
+	if r.err != nil {
+		return ocispec.Descriptor{}, r.err
+	}
+	return r.ReadOnlyTarget.Resolve(ctx, reference)
+}
+
+func (r *testRegistry) Fetch(ctx context.Context, target ocispec.Descriptor) (rc io.ReadCloser, err error) {
+	r.calls++
+	if r.err != nil {
+		return nil, r.err
+	}
+	return r.ReadOnlyTarget.Fetch(ctx, target)
+}
+
+// newTestRegistry returns a registry holding one image tagged `1.0.0`
+// and by its digest.
+func newTestRegistry(t *testing.T) (*testRegistry, digest.Digest) {
+	t.Helper()
+	ctx := context.Background()
+	store := memory.New()
+
+	config := []byte("{}")
+	configDesc := content.NewDescriptorFromBytes(ocispec.MediaTypeImageConfig, config)
+	require.NoError(t, store.Push(ctx, configDesc, bytes.NewReader(config)))
+
+	manifest, err := json.Marshal(ocispec.Manifest{
+		Versioned: specs.Versioned{SchemaVersion: 2},
+		Config:    configDesc,
+		Layers:    []ocispec.Descriptor{},
+	})
+	require.NoError(t, err)
+	manifestDesc := content.NewDescriptorFromBytes(ocispec.MediaTypeImageManifest, manifest)
+	require.NoError(t, store.Push(ctx, manifestDesc, bytes.NewReader(manifest)))
+	require.NoError(t, store.Tag(ctx, manifestDesc, "1.0.0"))
+	require.NoError(t, store.Tag(ctx, manifestDesc, manifestDesc.Digest.String()))
+
+	return &testRegistry{ReadOnlyTarget: store}, manifestDesc.Digest
+}
+
+func TestCacheImageDigest(t *testing.T) {
+	ctx := context.Background()
+	src, dgst := newTestRegistry(t)
+	cache := memory.New()
+	ref := registry.Reference{
+		Registry:   "localhost:5000",
+		Repository: "nanobus/app",
+		Reference:  dgst.String(),
+	}
+
+	// The registry copy reports progress through the copy options.
+	var mu sync.Mutex
+	var copied []digest.Digest
+	opts := oras.DefaultCopyOptions
+	opts.PostCopy = func(ctx context.Context, desc ocispec.Descriptor) error {
+		mu.Lock()
+		defer mu.Unlock()
+		copied = append(copied, desc.Digest)
+		return nil
+	}
+	require.NoError(t, cacheImage(ctx, src, ref, cache, ref.String(), opts))
+	assert.NotZero(t, src.calls)
+	assert.Contains(t, copied, dgst)
+	desc, err := cache.Resolve(ctx, ref.String())
+	require.NoError(t, err)
+	assert.Equal(t, dgst, desc.Digest)
+
+	// The second fetch is served from the cache without a registry call.
+	src.calls = 0
+	copied = nil
+	require.NoError(t, cacheImage(ctx, src, ref, cache, ref.String(), opts))
+	assert.Zero(t, src.calls)
+	assert.Empty(t, copied)
+}
+
+func TestCacheImageTagUnavailable(t *testing.T) {
+	ctx := context.Background()
+	src, dgst := newTestRegistry(t)
+	cache := memory.New()
+	ref := registry.Reference{
+		Registry:   "localhost:5000",
+		Repository: "nanobus/app",
+		Reference:  "1.0.0",
+	}
+	unavailable := &errcode.ErrorResponse{StatusCode: http.StatusServiceUnavailable}
+
+	// Nothing is cached yet, so the registry error is returned.
+	src.err = unavailable
+	err := cacheImage(ctx, src, ref, cache, ref.String(), oras.DefaultCopyOptions)
+	assert.ErrorAs(t, err, &unavailable)
+
+	// Once cached, the tag falls back to the cache.
+	src.err = nil
+	require.NoError(t, cacheImage(ctx, src, ref, cache, ref.String(), oras.DefaultCopyOptions))
+	src.err = unavailable
+	require.NoError(t, cacheImage(ctx, src, ref, cache, ref.String(), oras.DefaultCopyOptions))
+	desc, err := cache.Resolve(ctx, ref.String())
+	require.NoError(t, err)
+	assert.Equal(t, dgst, desc.Digest)
+
+	// Errors that do not mean the registry is unavailable are returned.
+	src.err = &errcode.ErrorResponse{StatusCode: http.StatusUnauthorized}
+	assert.Error(t, cacheImage(ctx, src, ref, cache, ref.String(), oras.DefaultCopyOptions))
+}
+
+func TestOpenCacheLocks(t *testing.T) {
+	t.Setenv("OCI_CACHE_DIR", filepath.Join(t.TempDir(), "oci"))
+
+	_, release, err := openCache()
+	require.NoError(t, err)
+
+	opened := make(chan func())
+	go func() {
+		_, release, err := openCache()
+		if err != nil {
+			release = func() {}
+		}
+		opened <- release
+	}()
+
+	select {
+	case <-opened:
+		t.Fatal("cache opened while another pull held the lock")
+	case <-time.After(100 * time.Millisecond):
+	}
+
+	release()
+	select {
+	case release := <-opened:
+		release()
+	case <-time.After(5 * time.Second):
+		t.Fatal("cache still locked after release")
+	}
+}
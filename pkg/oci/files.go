@@ -27,9 +27,11 @@ var mediaTypeFilenames = map[string]string{
 	"apex.yaml": ApexConfigMediaType,
 }
 
-var reImageReference = regexp.MustCompile(`(?m)^([a-zA-Z0-9\-\.]+)\/([a-zA-Z0-9\-\.]+)\/([a-zA-Z0-9\-\.]+):([a-zA-Z0-9\-\.]+)$`)
+var reImageReference = regexp.MustCompile(`(?m)^([a-zA-Z0-9\-\.]+)\/([a-zA-Z0-9\-\.]+)\/([a-zA-Z0-9\-\.]+)(:([a-zA-Z0-9\-\.]+)|@sha256:[a-f0-9]{64})$`)
 
-// IsImageReference tests if a string is an OCI image reference.
+// IsImageReference tests if a string is an OCI image reference. The
+// image can be referenced by tag (`host/org/repo:tag`) or pinned by
+// digest (`host/org/repo@sha256:...`).
 func IsImageReference(location string) bool {
 	return reImageReference.MatchString(location)
 }
@@ -0,0 +1,41 @@
+package oci
+
+import (
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+)
+
+func TestIsImageReference(t *testing.T) {
+	tests := map[string]struct {
+		location string
+		expected bool
+	}{
+		"tag": {
+			location: "reg.candle.run/nanobus/app:1.0.0",
+			expected: true,
+		},
+		"digest": {
+			location: "reg.candle.run/nanobus/app@sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef",
+			expected: true,
+		},
+		"short digest": {
+			location: "reg.candle.run/nanobus/app@sha256:0123456789abcdef",
+			expected: false,
+		},
+		"no tag": {
+			location: "reg.candle.run/nanobus/app",
+			expected: false,
+		},
+		"file": {
+			location: "bus.yaml",
+			expected: false,
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			assert.Equal(t, tt.expected, IsImageReference(tt.location))
+		})
+	}
+}
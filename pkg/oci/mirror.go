@@ -0,0 +1,55 @@
+package oci
+
+import (
+	"errors"
+	"net"
+	"net/http"
+	"os"
+	"strings"
+
+	"oras.land/oras-go/v2/registry"
+	"oras.land/oras-go/v2/registry/remote/errcode"
+)
+
+// envMirrors returns the registry mirrors configured with the
+// `OCI_MIRRORS` environment variable.
+func envMirrors() []string {
+	mirrorsString := os.Getenv("OCI_MIRRORS")
+	if mirrorsString == "" {
+		return nil
+	}
+
+	var mirrors []string
+	for _, mirror := range strings.Split(mirrorsString, ",") {
+		if mirror = strings.TrimSpace(mirror); mirror != "" {
+			mirrors = append(mirrors, mirror)
+		}
+	}
+	return mirrors
+}
+
+// mirrorReference rewrites the registry of reference to mirror, keeping
+// the repository and the tag or digest.
+func mirrorReference(reference, mirror string) (string, error) {
+	ref, err := registry.ParseReference(reference)
+	if err != nil {
+		return "", err
+	}
+	ref.Registry = mirror
+	if err := ref.ValidateRegistry(); err != nil {
+		return "", err
+	}
+	return ref.String(), nil
+}
+
+// isUnavailable tests if err means the registry could not serve the
+// request at all, as opposed to rejecting it.
+func isUnavailable(err error) bool {
+	var errResp *errcode.ErrorResponse
+	if errors.As(err, &errResp) {
+		return errResp.StatusCode >= http.StatusInternalServerError
+	}
+
+	var netErr net.Error
+	return errors.As(err, &netErr)
+}
@@ -0,0 +1,145 @@
+package oci
+
+import (
+	"errors"
+	"net"
+	"net/http"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"oras.land/oras-go/v2/registry/remote/errcode"
+)
+
+func TestMirrorReference(t *testing.T) {
+	tests := map[string]struct {
+		reference string
+		mirror    string
+		expected  string
+	}{
+		"tag": {
+			reference: "reg.candle.run/nanobus/app:1.0.0",
+			mirror:    "mirror.example.com",
+			expected:  "mirror.example.com/nanobus/app:1.0.0",
+		},
+		"port": {
+			reference: "reg.candle.run/nanobus/app:1.0.0",
+			mirror:    "localhost:5000",
+			expected:  "localhost:5000/nanobus/app:1.0.0",
+		},
+		"digest": {
+			reference: "reg.candle.run/nanobus/app@sha256:b5b2b2c507a0944348e0303114d8d93aaaa081732b86451d9bce1f432a537bc7",
+			mirror:    "mirror.example.com",
+			expected:  "mirror.example.com/nanobus/app@sha256:b5b2b2c507a0944348e0303114d8d93aaaa081732b86451d9bce1f432a537bc7",
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			actual, err := mirrorReference(tt.reference, tt.mirror)
+			require.NoError(t, err)
+			assert.Equal(t, tt.expected, actual)
+		})
+	}
+
+	_, err := mirrorReference("reg.candle.run/nanobus/app:1.0.0", "not a host")
+	assert.Error(t, err)
+}
+
+func TestEnvMirrors(t *testing.T) {
+	t.Setenv("OCI_MIRRORS", " mirror1.example.com, ,mirror2.example.com")
+	assert.Equal(t, []string{"mirror1.example.com", "mirror2.example.com"}, envMirrors())
+
+	t.Setenv("OCI_MIRRORS", "")
+	assert.Empty(t, envMirrors())
+}
+
+func TestIsUnavailable(t *testing.T) {
+	assert.True(t, isUnavailable(&errcode.ErrorResponse{StatusCode: http.StatusBadGateway}))
+	assert.False(t, isUnavailable(&errcode.ErrorResponse{StatusCode: http.StatusNotFound}))
+	assert.True(t, isUnavailable(&net.OpError{Op: "dial", Err: errors.New("connection refused")}))
+	assert.False(t, isUnavailable(errors.New("invalid reference")))
+}
+
+func TestPullWithMirrors(t *testing.T) {
+	const reference = "reg.candle.run/nanobus/app:1.0.0"
+	unavailable := &errcode.ErrorResponse{StatusCode: http.StatusServiceUnavailable}
+	notFound := &errcode.ErrorResponse{StatusCode: http.StatusNotFound}
+
+	tests := map[string]struct {
+		results  map[string]error
+		appFile  string
+		pulled   []string
+		contains []error
+	}{
+		"primary": {
+			results: map[string]error{},
+			appFile: "app.wasm",
+			pulled:  []string{reference},
+		},
+		"primary rejects": {
+			results:  map[string]error{reference: notFound},
+			pulled:   []string{reference},
+			contains: []error{notFound},
+		},
+		"mirror after failed mirror": {
+			results: map[string]error{
+				reference:                               unavailable,
+				"mirror1.example.com/nanobus/app:1.0.0": notFound,
+			},
+			appFile: "app.wasm",
+			pulled:  []string{
+				reference,
+				"mirror1.example.com/nanobus/app:1.0.0",
+				"mirror2.example.com/nanobus/app:1.0.0",
+			},
+		},
+		"all fail": {
+			results: map[string]error{
+				reference:                               unavailable,
+				"mirror1.example.com/nanobus/app:1.0.0": notFound,
+				"mirror2.example.com/nanobus/app:1.0.0": errors.New("digest mismatch"),
+			},
+			pulled: []string{
+				reference,
+				"mirror1.example.com/nanobus/app:1.0.0",
+				"mirror2.example.com/nanobus/app:1.0.0",
+			},
+			contains: []error{unavailable, notFound},
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			var pulled []string
+			appFile, err := pullWithMirrors(reference, []string{"mirror1.example.com", "mirror2.example.com"},
+				func(reference string) (string, error) {
+					pulled = append(pulled, reference)
+					if err := tt.results[reference]; err != nil {
+						return "", err
+					}
+					return "app.wasm", nil
+				})
+
+			assert.Equal(t, tt.pulled, pulled)
+			assert.Equal(t, tt.appFile, appFile)
+			if tt.contains == nil {
+				assert.NoError(t, err)
+			}
+			for _, expected := range tt.contains {
+				assert.ErrorIs(t, err, expected)
+			}
+		})
+	}
+}
+
+func TestPullKeepsMirrors(t *testing.T) {
+	t.Setenv("OCI_MIRRORS", "mirror2.example.com")
+	mirrors := make([]string, 1, 2)
+	mirrors[0] = "mirror1.example.com"
+
+	// The reference is invalid, so nothing is pulled.
+	_, err := Pull("", t.TempDir(), mirrors...)
+	assert.Error(t, err)
+	assert.Equal(t, []string{"mirror1.example.com", ""}, mirrors[:2])
+}
@@ -8,12 +8,54 @@ import (
 
 	"github.com/opencontainers/go-digest"
 	ocispec "github.com/opencontainers/image-spec/specs-go/v1"
+	"go.uber.org/multierr"
 	"oras.land/oras-go/v2"
 	"oras.land/oras-go/v2/content"
 	"oras.land/oras-go/v2/content/file"
 )
 
-func Pull(reference, target string) (string, error) {
+// Pull downloads the image at reference into the target directory and
+// returns the name of the application file it contains, if any. When the
+// registry is unreachable or returns a server error, the same repository
+// is pulled from each mirror in turn. Mirrors are taken from the
+// arguments followed by the comma-separated `OCI_MIRRORS` environment
+// variable. Only digest references guarantee that a mirror serves the
+// same bytes as the primary registry.
+func Pull(reference, target string, mirrors ...string) (string, error) {
+	candidates := append(append([]string{}, mirrors...), envMirrors()...)
+	return pullWithMirrors(reference, candidates, func(reference string) (string, error) {
+		return pull(reference, target)
+	})
+}
+
+// pullWithMirrors pulls reference and, if its registry is unavailable,
+// tries each mirror in turn. A mirror that fails for any reason is
+// skipped. When every candidate fails, the returned error includes the
+// error of each one.
+func pullWithMirrors(reference string, mirrors []string, pull func(reference string) (string, error)) (string, error) {
+	appFile, err := pull(reference)
+	if err == nil || !isUnavailable(err) || len(mirrors) == 0 {
+		return appFile, err
+	}
+
+	errs := []error{fmt.Errorf("%s: %w", reference, err)}
+	for _, mirror := range mirrors {
+		mirrored, merr := mirrorReference(reference, mirror)
+		if merr != nil {
+			errs = append(errs, fmt.Errorf("mirror %s: %w", mirror, merr))
+			continue
+		}
+		fmt.Println("Pulling from mirror", mirrored)
+		if appFile, merr = pull(mirrored); merr == nil {
+			return appFile, nil
+		}
+		errs = append(errs, fmt.Errorf("%s: %w", mirrored, merr))
+	}
+
+	return "", multierr.Combine(errs...)
+}
+
+func pull(reference, target string) (string, error) {
 	verbose := false
 	var printed sync.Map
 	var targetPlatform *ocispec.Platform
@@ -30,69 +72,95 @@ func Pull(reference, target string) (string, error) {
 	if targetPlatform != nil {
 		copyOptions.WithTargetPlatform(targetPlatform)
 	}
-	copyOptions.FindSuccessors = func(ctx context.Context, fetcher content.Fetcher, desc ocispec.Descriptor) ([]ocispec.Descriptor, error) {
-		statusFetcher := content.FetcherFunc(func(ctx context.Context, target ocispec.Descriptor) (fetched io.ReadCloser, fetchErr error) {
-			if _, ok := printed.LoadOrStore(generateContentKey(target), true); ok {
-				return fetcher.Fetch(ctx, target)
-			}
-
-			// print status log for first-time fetching
-			if err := PrintStatus(target, "Downloading", verbose); err != nil {
-				return nil, err
-			}
-			rc, err := fetcher.Fetch(ctx, target)
-			if err != nil {
-				return nil, err
-			}
-			defer func() {
-				if fetchErr != nil {
-					rc.Close()
+	// findSuccessors skips unnamed leaf nodes. When printStatus is set,
+	// manifests fetched for the first time are reported as downloads.
+	findSuccessors := func(printStatus bool) func(context.Context, content.Fetcher, ocispec.Descriptor) ([]ocispec.Descriptor, error) {
+		return func(ctx context.Context, fetcher content.Fetcher, desc ocispec.Descriptor) ([]ocispec.Descriptor, error) {
+			statusFetcher := content.FetcherFunc(func(ctx context.Context, target ocispec.Descriptor) (fetched io.ReadCloser, fetchErr error) {
+				if !printStatus {
+					return fetcher.Fetch(ctx, target)
 				}
-			}()
-			if err := PrintStatus(target, "Processing ", verbose); err != nil {
-				return nil, err
-			}
-			return rc, nil
-		})
-		successors, err := content.Successors(ctx, statusFetcher, desc)
-		if err != nil {
-			return nil, err
-		}
-		var ret []ocispec.Descriptor
-		// Iterate all the successors to
-		// 1) Add name annotation to config if configPath is not empty
-		// 2) Skip fetching unnamed leaf nodes
-		for i, s := range successors {
-			// Save the config when:
-			// 1) MediaType matches, or
-			// 2) MediaType not specified and current node is config.
-			// Note: For a manifest, the 0th indexed element is always a
-			// manifest config.
-			if (s.MediaType == configMediaType || (configMediaType == "" && i == 0 && IsImageManifest(desc))) && configPath != "" {
-				// Add annotation for manifest config
-				if s.Annotations == nil {
-					s.Annotations = make(map[string]string)
+				if _, ok := printed.LoadOrStore(generateContentKey(target), true); ok {
+					return fetcher.Fetch(ctx, target)
 				}
-				s.Annotations[ocispec.AnnotationTitle] = configPath
-			}
-			if s.Annotations[ocispec.AnnotationTitle] == "" {
-				ss, err := content.Successors(ctx, fetcher, s)
+
+				// print status log for first-time fetching
+				if err := PrintStatus(target, "Downloading", verbose); err != nil {
+					return nil, err
+				}
+				rc, err := fetcher.Fetch(ctx, target)
 				if err != nil {
 					return nil, err
 				}
-				// Skip s if s is unnamed and has no successors.
-				if len(ss) == 0 {
-					if _, loaded := printed.LoadOrStore(generateContentKey(s), true); !loaded {
-						if err = PrintStatus(s, "Skipped    ", verbose); err != nil {
-							return nil, err
+				defer func() {
+					if fetchErr != nil {
+						rc.Close()
+					}
+				}()
+				if err := PrintStatus(target, "Processing ", verbose); err != nil {
+					return nil, err
+				}
+				return rc, nil
+			})
+			successors, err := content.Successors(ctx, statusFetcher, desc)
+			if err != nil {
+				return nil, err
+			}
+			var ret []ocispec.Descriptor
+			// Iterate all the successors to
+			// 1) Add name annotation to config if configPath is not empty
+			// 2) Skip fetching unnamed leaf nodes
+			for i, s := range successors {
+				// Save the config when:
+				// 1) MediaType matches, or
+				// 2) MediaType not specified and current node is config.
+				// Note: For a manifest, the 0th indexed element is always a
+				// manifest config.
+				if (s.MediaType == configMediaType || (configMediaType == "" && i == 0 && IsImageManifest(desc))) && configPath != "" {
+					// Add annotation for manifest config
+					if s.Annotations == nil {
+						s.Annotations = make(map[string]string)
+					}
+					s.Annotations[ocispec.AnnotationTitle] = configPath
+				}
+				if s.Annotations[ocispec.AnnotationTitle] == "" {
+					ss, err := content.Successors(ctx, fetcher, s)
+					if err != nil {
+						return nil, err
+					}
+					// Skip s if s is unnamed and has no successors.
+					if len(ss) == 0 {
+						if _, loaded := printed.LoadOrStore(generateContentKey(s), true); !loaded {
+							if err = PrintStatus(s, "Skipped    ", verbose); err != nil {
+								return nil, err
+							}
 						}
+						continue
 					}
-					continue
 				}
+				ret = append(ret, s)
 			}
-			ret = append(ret, s)
+			return ret, nil
 		}
-		return ret, nil
+	}
+
+	// The registry copy fills the cache and reports download progress.
+	var downloaded sync.Map
+	downloadOptions := copyOptions
+	downloadOptions.FindSuccessors = findSuccessors(true)
+	downloadOptions.PreCopy = func(ctx context.Context, desc ocispec.Descriptor) error {
+		if _, ok := printed.LoadOrStore(generateContentKey(desc), true); ok {
+			return nil
+		}
+		return PrintStatus(desc, "Downloading", verbose)
+	}
+	downloadOptions.PostCopy = func(ctx context.Context, desc ocispec.Descriptor) error {
+		downloaded.Store(generateContentKey(desc), true)
+		return PrintStatus(desc, "Downloaded ", verbose)
+	}
+	downloadOptions.OnCopySkipped = func(ctx context.Context, desc ocispec.Descriptor) error {
+		downloaded.Store(generateContentKey(desc), true)
+		return PrintStatus(desc, "Cached     ", verbose)
 	}
 
 	appFile := ""
@@ -101,13 +169,9 @@ func Pull(reference, target string) (string, error) {
 		return "", err
 	}
 
+	// The local copy writes the files out of the cache.
 	pulledEmpty := true
-	copyOptions.PreCopy = func(ctx context.Context, desc ocispec.Descriptor) error {
-		if _, ok := printed.LoadOrStore(generateContentKey(desc), true); ok {
-			return nil
-		}
-		return PrintStatus(desc, "Downloading", verbose)
-	}
+	copyOptions.FindSuccessors = findSuccessors(false)
 	copyOptions.PostCopy = func(ctx context.Context, desc ocispec.Descriptor) error {
 		// restore named but deduplicated successor nodes
 		successors, err := content.Successors(ctx, dst, desc)
@@ -124,10 +188,7 @@ func Pull(reference, target string) (string, error) {
 			}
 		}
 
-		name, ok := desc.Annotations[ocispec.AnnotationTitle]
-		if !ok {
-			name = desc.MediaType
-		} else {
+		if name, ok := desc.Annotations[ocispec.AnnotationTitle]; ok {
 			// named content downloaded
 			pulledEmpty = false
 
@@ -137,11 +198,38 @@ func Pull(reference, target string) (string, error) {
 			}
 		}
 		printed.Store(generateContentKey(desc), true)
-		return Print("Downloaded ", ShortDigest(desc), name)
+
+		// Content the registry copy did not report came from the cache.
+		if _, ok := downloaded.Load(generateContentKey(desc)); ok {
+			return nil
+		}
+		return PrintStatus(desc, "Cached     ", verbose)
+	}
+
+	// Fetch into the local cache, then copy the files out of it.
+	cache, release, err := openCache()
+	if err != nil {
+		return "", err
+	}
+	defer release()
+	cacheRef := repo.Reference.String()
+	if err := cacheImage(ctx, repo, repo.Reference, cache, cacheRef, downloadOptions); err != nil {
+		return "", err
+	}
+
+	// Verify pinned images before copying anything out of the cache.
+	if expected, err := repo.Reference.Digest(); err == nil {
+		desc, err := cache.Resolve(ctx, cacheRef)
+		if err != nil {
+			return "", err
+		}
+		if err := verifyDigest(ctx, cache, desc, expected); err != nil {
+			return "", fmt.Errorf("could not verify %s: %w", reference, err)
+		}
 	}
 
 	// Copy
-	desc, err := oras.Copy(ctx, repo, repo.Reference.Reference, dst, repo.Reference.Reference, copyOptions)
+	desc, err := oras.Copy(ctx, cache, cacheRef, dst, repo.Reference.Reference, copyOptions)
 	if err != nil {
 		return "", err
 	}
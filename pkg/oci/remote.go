@@ -91,6 +91,11 @@ func getRepository(reference string) (*remote.Repository, error) {
 		return nil, fmt.Errorf("could not access repository %s: %w", reference, err)
 	}
 
+	if isInsecureRegistry(repo.Reference.Registry) {
+		fmt.Println("WARNING: accessing registry", repo.Reference.Registry, "over plain HTTP")
+		repo.PlainHTTP = true
+	}
+
 	if credential, ok := repos[repo.Reference.Registry]; ok {
 		repo.Client = &auth.Client{
 			Credential: auth.StaticCredential(repo.Reference.Registry, credential),
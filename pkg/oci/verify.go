@@ -0,0 +1,49 @@
+package oci
+
+import (
+	"context"
+	"fmt"
+	"os"
+	"strings"
+
+	"github.com/opencontainers/go-digest"
+	ocispec "github.com/opencontainers/image-spec/specs-go/v1"
+	"oras.land/oras-go/v2/content"
+)
+
+// verifyDigest fetches the content described by desc and checks that it
+// hashes to expected. It guards digest references against content that
+// was altered in a mirror or in the local cache.
+func verifyDigest(ctx context.Context, fetcher content.Fetcher, desc ocispec.Descriptor, expected digest.Digest) error {
+	if desc.Digest != expected {
+		return fmt.Errorf("resolved digest %s does not match reference digest %s", desc.Digest, expected)
+	}
+
+	rc, err := fetcher.Fetch(ctx, desc)
+	if err != nil {
+		return err
+	}
+	defer rc.Close()
+
+	actual, err := expected.Algorithm().FromReader(rc)
+	if err != nil {
+		return err
+	}
+	if actual != expected {
+		return fmt.Errorf("content digest %s does not match reference digest %s", actual, expected)
+	}
+
+	return nil
+}
+
+// isInsecureRegistry tests if registry is listed in the comma-separated
+// `OCI_INSECURE_REGISTRIES` environment variable and may therefore be
+// accessed over plain HTTP.
+func isInsecureRegistry(registry string) bool {
+	for _, insecure := range strings.Split(os.Getenv("OCI_INSECURE_REGISTRIES"), ",") {
+		if strings.TrimSpace(insecure) == registry {
+			return true
+		}
+	}
+	return false
+}
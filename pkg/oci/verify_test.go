@@ -0,0 +1,68 @@
+package oci
+
+import (
+	"bytes"
+	"context"
+	"io"
+	"testing"
+
+	"github.com/opencontainers/go-digest"
+	ocispec "github.com/opencontainers/image-spec/specs-go/v1"
+	"github.com/stretchr/testify/assert"
+	"oras.land/oras-go/v2/content"
+)
+
+func TestVerifyDigest(t *testing.T) {
+	blob := []byte(`{"schemaVersion":2}`)
+	expected := digest.FromBytes(blob)
+	desc := ocispec.Descriptor{
+		MediaType: ocispec.MediaTypeImageManifest,
+		Digest:    expected,
+		Size:      int64(len(blob)),
+	}
+
+	tests := map[string]struct {
+		desc    ocispec.Descriptor
+		blob    []byte
+		wantErr string
+	}{
+		"match": {
+			desc: desc,
+			blob: blob,
+		},
+		"tampered": {
+			desc:    desc,
+			blob:    []byte(`{"schemaVersion":3}`),
+			wantErr: "content digest " + digest.FromBytes([]byte(`{"schemaVersion":3}`)).String() + " does not match reference digest " + expected.String(),
+		},
+		"resolved elsewhere": {
+			desc: ocispec.Descriptor{
+				MediaType: ocispec.MediaTypeImageManifest,
+				Digest:    digest.FromString("other"),
+			},
+			blob:    blob,
+			wantErr: "resolved digest " + digest.FromString("other").String() + " does not match reference digest " + expected.String(),
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			fetcher := content.FetcherFunc(func(ctx context.Context, target ocispec.Descriptor) (io.ReadCloser, error) {
+				return io.NopCloser(bytes.NewReader(tt.blob)), nil
+			})
+			err := verifyDigest(context.Background(), fetcher, tt.desc, expected)
+			if tt.wantErr != "" {
+				assert.EqualError(t, err, tt.wantErr)
+			} else {
+				assert.NoError(t, err)
+			}
+		})
+	}
+}
+
+func TestIsInsecureRegistry(t *testing.T) {
+	t.Setenv("OCI_INSECURE_REGISTRIES", "localhost:5000, registry.local")
+	assert.True(t, isInsecureRegistry("localhost:5000"))
+	assert.True(t, isInsecureRegistry("registry.local"))
+	assert.False(t, isInsecureRegistry("reg.candle.run"))
+}
@@ -24,6 +24,7 @@ import (
 	"github.com/nanobus/nanobus/pkg/actions"
 	"github.com/nanobus/nanobus/pkg/channel"
 	"github.com/nanobus/nanobus/pkg/compute"
+	"github.com/nanobus/nanobus/pkg/errorz"
 	"github.com/nanobus/nanobus/pkg/logger"
 	"github.com/nanobus/nanobus/pkg/security/claims"
 	"github.com/nanobus/nanobus/pkg/stream"
@@ -110,7 +111,11 @@ func (i *Invoker) Operations() operations.Table {
 }
 
 func (i *Invoker) FireAndForget(ctx context.Context, p payload.Payload) {
-	r, data := i.lookup(ctx, p)
+	r, data, err := i.lookup(ctx, p)
+	if err != nil {
+		logger.Error("error with FireAndForget request", "error", err)
+		return
+	}
 	go func() {
 		if _, err := r(ctx, data); err != nil {
 			logger.Error("error with FireAndForget request", "error", err)
@@ -119,8 +124,12 @@ func (i *Invoker) FireAndForget(ctx context.Context, p payload.Payload) {
 }
 
 func (i *Invoker) RequestResponse(ctx context.Context, p payload.Payload) mono.Mono[payload.Payload] {
-	r, data := i.lookup(ctx, p)
+	r, data, err := i.lookup(ctx, p)
 	return mono.Create(func(sink mono.Sink[payload.Payload]) {
+		if err != nil {
+			sink.Error(err)
+			return
+		}
 		go func() {
 			result, err := r(ctx, data)
 			if err != nil {
@@ -145,8 +154,12 @@ func (i *Invoker) RequestResponse(ctx context.Context, p payload.Payload) mono.M
 }
 
 func (i *Invoker) RequestStream(ctx context.Context, p payload.Payload) flux.Flux[payload.Payload] {
-	r, data := i.lookup(ctx, p)
+	r, data, err := i.lookup(ctx, p)
 	return flux.Create(func(sink flux.Sink[payload.Payload]) {
+		if err != nil {
+			sink.Error(err)
+			return
+		}
 		go func() {
 			s := stream.FromSink(sink)
 			ctx = stream.SinkNewContext(ctx, s)
@@ -167,8 +180,12 @@ func (i *Invoker) RequestStream(ctx context.Context, p payload.Payload) flux.Flu
 }
 
 func (i *Invoker) RequestChannel(ctx context.Context, p payload.Payload, in flux.Flux[payload.Payload]) flux.Flux[payload.Payload] {
-	r, data := i.lookup(ctx, p)
+	r, data, err := i.lookup(ctx, p)
 	return flux.Create(func(sink flux.Sink[payload.Payload]) {
+		if err != nil {
+			sink.Error(err)
+			return
+		}
 		go func() {
 			streamSink := stream.FromSink(sink)
 			ctx = stream.SinkNewContext(ctx, streamSink)
@@ -191,17 +208,20 @@ func (i *Invoker) RequestChannel(ctx context.Context, p payload.Payload, in flux
 	})
 }
 
-func (i *Invoker) lookup(ctx context.Context, p payload.Payload) (Runnable, actions.Data) {
+func (i *Invoker) lookup(ctx context.Context, p payload.Payload) (Runnable, actions.Data, error) {
 	md := p.Metadata()
 	index := binary.BigEndian.Uint32(md)
 	r := i.runnables[index]
 	t := i.targets[index]
 	var input interface{}
-	if err := i.codec.Decode(p.Data(), &input); err != nil {
-		// TODO(jsoverson): improve error logging. This bypasses a log error if we got
-		// no payload data but we should log an error if we expected some.
-		if len(p.Data()) > 0 {
+	// An empty payload means the operation was invoked without input.
+	// Anything else must decode, otherwise the failure would surface
+	// later as an opaque error from inside the pipeline.
+	if len(p.Data()) > 0 {
+		if err := i.codec.Decode(p.Data(), &input); err != nil {
 			logger.Warn("received error when decoding payload", "action", t.String(), "error", err)
+			return nil, nil, errorz.Wrap(err, errorz.InvalidArgument,
+				fmt.Sprintf("malformed input for %s: %v", t.String(), err))
 		}
 	}
 	c := claims.FromContext(ctx)
@@ -216,7 +236,7 @@ func (i *Invoker) lookup(ctx context.Context, p payload.Payload) (Runnable, acti
 		logOutbound(t.String(), string(jsonBytes))
 	}
 
-	return r, data
+	return r, data, nil
 }
 
 func isNil(val interface{}) bool {
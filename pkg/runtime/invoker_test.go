@@ -0,0 +1,76 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package runtime_test
+
+import (
+	"context"
+	"errors"
+	"testing"
+
+	"github.com/go-logr/logr"
+	"github.com/nanobus/iota/go/payload"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/nanobus/nanobus/pkg/actions"
+	msgpack_codec "github.com/nanobus/nanobus/pkg/channel/codecs/msgpack"
+	"github.com/nanobus/nanobus/pkg/errorz"
+	"github.com/nanobus/nanobus/pkg/runtime"
+)
+
+func TestInvokerInput(t *testing.T) {
+	codec := msgpack_codec.New()
+	valid, err := codec.Encode(map[string]interface{}{"name": "test"})
+	require.NoError(t, err)
+
+	tests := map[string]struct {
+		data   []byte
+		called bool
+		err    errorz.ErrCode
+	}{
+		"valid": {
+			data:   valid,
+			called: true,
+		},
+		"empty": {
+			data:   []byte{},
+			called: true,
+		},
+		"malformed": {
+			data: []byte{0xc1},
+			err:  errorz.InvalidArgument,
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			called := false
+			ns := runtime.Namespaces{
+				"test.v1": runtime.Functions{
+					"echo": func(ctx context.Context, data actions.Data) (interface{}, error) {
+						called = true
+						return data["input"], nil
+					},
+				},
+			}
+			invoker := runtime.NewInvoker(logr.Discard(), ns, codec)
+
+			_, err := invoker.RequestResponse(context.Background(), payload.New(tt.data, make([]byte, 8))).Block()
+			assert.Equal(t, tt.called, called)
+			if tt.err == errorz.OK {
+				assert.NoError(t, err)
+				return
+			}
+			var errz *errorz.Error
+			require.True(t, errors.As(err, &errz))
+			assert.Equal(t, tt.err, errz.Code)
+			assert.Contains(t, errz.Message, "test.v1::echo")
+		})
+	}
+}
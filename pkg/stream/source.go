@@ -10,6 +10,7 @@ package stream
 
 import (
 	"io"
+	"sync"
 	"sync/atomic"
 
 	"github.com/nanobus/iota/go/payload"
@@ -20,11 +21,25 @@ import (
 	"github.com/nanobus/nanobus/pkg/channel/metadata"
 )
 
+const (
+	// sourceCredits is the number of payloads the source grants the
+	// producer up front. The buffer holds all of them plus a terminal
+	// error so a producer that honors its credits never blocks delivery.
+	sourceCredits = 100
+	// sourceReplenish is the number of payloads consumed before more
+	// credits are granted back to the producer.
+	sourceReplenish = sourceCredits / 2
+)
+
 type source struct {
 	codec    channel.Codec
 	f        flux.Flux[payload.Payload]
 	ch       chan value
 	canceled atomic.Bool
+
+	mu       sync.Mutex
+	sub      rx.Subscription
+	consumed int
 }
 
 type value struct {
@@ -33,13 +48,22 @@ type value struct {
 }
 
 func SourceFromFlux(codec channel.Codec, f flux.Flux[payload.Payload]) Source {
-	ch := make(chan value, 100)
-	s := &source{
+	s := newSource(codec)
+	s.f = f
+	f.Subscribe(s.subscriber())
+	return s
+}
+
+func newSource(codec channel.Codec) *source {
+	return &source{
 		codec: codec,
-		f:     f,
-		ch:    ch,
+		ch:    make(chan value, sourceCredits+1),
 	}
-	f.Subscribe(flux.Subscribe[payload.Payload]{
+}
+
+func (s *source) subscriber() flux.Subscribe[payload.Payload] {
+	ch := s.ch
+	return flux.Subscribe[payload.Payload]{
 		OnNext: func(p payload.Payload) {
 			ch <- value{p: p}
 		},
@@ -51,14 +75,16 @@ func SourceFromFlux(codec channel.Codec, f flux.Flux[payload.Payload]) Source {
 			close(ch)
 		},
 		OnRequest: func(sub rx.Subscription) {
+			s.mu.Lock()
+			s.sub = sub
+			s.mu.Unlock()
 			if s.canceled.Load() {
 				sub.Cancel()
 			} else {
-				sub.Request(100)
+				sub.Request(sourceCredits)
 			}
 		},
-	})
-	return s
+	}
 }
 
 func (s *source) Next(data any, md *metadata.MD) error {
@@ -67,6 +93,7 @@ func (s *source) Next(data any, md *metadata.MD) error {
 		return val.err
 	}
 	if ok && val.p != nil {
+		s.replenish()
 		return s.codec.Decode(val.p.Data(), data)
 	}
 
@@ -75,4 +102,28 @@ func (s *source) Next(data any, md *metadata.MD) error {
 
 func (s *source) Cancel() {
 	s.canceled.Store(true)
+	s.mu.Lock()
+	sub := s.sub
+	s.mu.Unlock()
+	if sub != nil {
+		sub.Cancel()
+	}
+}
+
+// replenish grants the producer more credits once enough of the
+// outstanding ones have been consumed, so a slow reader bounds how far
+// ahead a fast producer can get. The lock is released before requesting
+// because a producer may deliver synchronously from Request.
+func (s *source) replenish() {
+	s.mu.Lock()
+	s.consumed++
+	if s.consumed < sourceReplenish || s.sub == nil || s.canceled.Load() {
+		s.mu.Unlock()
+		return
+	}
+	s.consumed = 0
+	sub := s.sub
+	s.mu.Unlock()
+
+	sub.Request(sourceReplenish)
 }
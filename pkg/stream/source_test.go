@@ -0,0 +1,147 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package stream
+
+import (
+	"encoding/json"
+	"io"
+	"strconv"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/nanobus/iota/go/payload"
+	"github.com/nanobus/iota/go/rx/flux"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+)
+
+type jsonCodec struct{}
+
+func (jsonCodec) ContentType() string { return "application/json" }
+func (jsonCodec) Encode(v interface{}) ([]byte, error) { return json.Marshal(v) }
+func (jsonCodec) Decode(data []byte, v interface{}) error { return json.Unmarshal(data, v) }
+
+// testSubscription is a producer that delivers synchronously from
+// Request, emitting no more payloads than it was granted.
+type testSubscription struct {
+	s     *source
+	subs  flux.Subscribe[payload.Payload]
+	total int
+
+	emitMu    sync.Mutex
+	completed bool
+
+	mu             sync.Mutex
+	requested      int
+	emitted        int
+	maxOutstanding int
+	canceled       bool
+}
+
+func (t *testSubscription) Request(n int) {
+	t.mu.Lock()
+	t.requested += n
+	// Demand not yet read by the consumer: not yet emitted plus buffered.
+	outstanding := t.requested - t.emitted + len(t.s.ch)
+	if outstanding > t.maxOutstanding {
+		t.maxOutstanding = outstanding
+	}
+	t.mu.Unlock()
+
+	t.emitMu.Lock()
+	defer t.emitMu.Unlock()
+	for {
+		t.mu.Lock()
+		if t.canceled || t.completed {
+			t.mu.Unlock()
+			return
+		}
+		if t.emitted >= t.total {
+			t.completed = true
+			t.mu.Unlock()
+			t.subs.OnComplete()
+			return
+		}
+		if t.emitted >= t.requested {
+			t.mu.Unlock()
+			return
+		}
+		t.emitted++
+		i := t.emitted
+		t.mu.Unlock()
+
+		t.subs.OnNext(payload.New([]byte(strconv.Itoa(i))))
+	}
+}
+
+func (t *testSubscription) Cancel() {
+	t.mu.Lock()
+	defer t.mu.Unlock()
+	t.canceled = true
+}
+
+func TestSourceCredits(t *testing.T) {
+	const total = 5 * sourceCredits
+
+	s := newSource(jsonCodec{})
+	subs := s.subscriber()
+	sub := &testSubscription{s: s, subs: subs, total: total}
+
+	done := make(chan struct{})
+	var received []int
+	go func() {
+		defer close(done)
+		for {
+			var i int
+			if err := s.Next(&i, nil); err != nil {
+				assert.Equal(t, io.EOF, err)
+				return
+			}
+			received = append(received, i)
+			if len(received)%10 == 0 {
+				time.Sleep(time.Millisecond)
+			}
+		}
+	}()
+
+	subs.OnRequest(sub)
+
+	select {
+	case <-done:
+	case <-time.After(5 * time.Second):
+		t.Fatal("reader did not finish")
+	}
+
+	require.Len(t, received, total)
+	for i, v := range received {
+		assert.Equal(t, i+1, v)
+	}
+	assert.LessOrEqual(t, sub.maxOutstanding, sourceCredits)
+}
+
+func TestSourceCancel(t *testing.T) {
+	s := newSource(jsonCodec{})
+	subs := s.subscriber()
+	sub := &testSubscription{s: s, subs: subs, total: 10}
+	subs.OnRequest(sub)
+
+	s.Cancel()
+	assert.True(t, sub.canceled)
+
+	// Canceling before the subscription is established cancels it once
+	// it arrives instead of requesting.
+	s = newSource(jsonCodec{})
+	subs = s.subscriber()
+	s.Cancel()
+	sub = &testSubscription{s: s, subs: subs, total: 10}
+	subs.OnRequest(sub)
+	assert.True(t, sub.canceled)
+	assert.Equal(t, 0, sub.requested)
+}
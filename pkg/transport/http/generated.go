@@ -3,6 +3,8 @@
 package http
 
 import (
+	"time"
+
 	"github.com/nanobus/nanobus/pkg/runtime"
 	"github.com/nanobus/nanobus/pkg/transport"
 )
@@ -10,14 +12,37 @@ import (
 // This component provides extensible HTTP server capabilities. The functionality
 // of your application is added by including `routers` and `middleware` components.
 type HttpServerV1Config struct {
-	// The listening address of the server.
+	// The listening address of the server. Use `unix:/path/to.sock` to listen on a
+	// Unix domain socket.
 	Address string `json:"address" yaml:"address" msgpack:"address" mapstructure:"address" validate:"required"`
 	// Array of [HTTP Middleware](/category/http-middleware) component configurations.
 	Middleware []runtime.Component `json:"middleware,omitempty" yaml:"middleware,omitempty" msgpack:"middleware,omitempty" mapstructure:"middleware" validate:"dive"`
 	// Array of [HTTP Router](/category/http-routers) component configurations.
 	Routers []runtime.Component `json:"routers,omitempty" yaml:"routers,omitempty" msgpack:"routers,omitempty" mapstructure:"routers" validate:"dive"`
+	// The maximum time to wait for in-flight requests to complete when the server
+	// shuts down. Connections still active afterwards are closed. Defaults to 10s.
+	DrainTimeout *time.Duration `json:"drainTimeout,omitempty" yaml:"drainTimeout,omitempty" msgpack:"drainTimeout,omitempty" mapstructure:"drainTimeout"`
+	// The interval between TCP keep-alive probes on accepted connections. Probes keep
+	// idle long-lived connections from being dropped by intermediary proxies. Defaults
+	// to 15s. A negative value disables keep-alive probes.
+	KeepAlive *time.Duration `json:"keepAlive,omitempty" yaml:"keepAlive,omitempty" msgpack:"keepAlive,omitempty" mapstructure:"keepAlive"`
+	// The maximum time to keep an idle keep-alive connection open while waiting for
+	// the next request. Defaults to no timeout.
+	IdleTimeout *time.Duration `json:"idleTimeout,omitempty" yaml:"idleTimeout,omitempty" msgpack:"idleTimeout,omitempty" mapstructure:"idleTimeout"`
+	// Serves HTTPS using the configured certificate instead of plain HTTP.
+	TLS *TLS `json:"tls,omitempty" yaml:"tls,omitempty" msgpack:"tls,omitempty" mapstructure:"tls"`
 }
 
 func HttpServerV1() (string, transport.Loader) {
 	return "nanobus.transport.http.server/v1", HttpServerV1Loader
 }
+
+type TLS struct {
+	// Path to the PEM encoded server certificate.
+	CertFile string `json:"certFile" yaml:"certFile" msgpack:"certFile" mapstructure:"certFile" validate:"required"`
+	// Path to the PEM encoded private key of the server certificate.
+	KeyFile string `json:"keyFile" yaml:"keyFile" msgpack:"keyFile" mapstructure:"keyFile" validate:"required"`
+	// Path to a PEM encoded CA bundle. When set, clients must present a
+	// certificate signed by one of these CAs (mutual TLS).
+	ClientCAFile *string `json:"clientCAFile,omitempty" yaml:"clientCAFile,omitempty" msgpack:"clientCAFile,omitempty" mapstructure:"clientCAFile"`
+}
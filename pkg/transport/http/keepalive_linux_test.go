@@ -0,0 +1,76 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package http
+
+import (
+	"net"
+	"syscall"
+	"testing"
+	"time"
+
+	"github.com/go-logr/logr"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/otel/trace"
+)
+
+func TestListenKeepAlive(t *testing.T) {
+	duration := func(d time.Duration) *time.Duration { return &d }
+
+	tests := map[string]struct {
+		keepAlive *time.Duration
+		enabled   int
+		interval  int
+	}{
+		"default":    {enabled: 1, interval: 15},
+		"configured": {keepAlive: duration(5 * time.Second), enabled: 1, interval: 5},
+		"disabled":   {keepAlive: duration(-1), enabled: 0},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			server, err := NewServer(logr.Discard(), trace.NewNoopTracerProvider().Tracer(""),
+				HttpServerV1Config{
+					Address:   "127.0.0.1:0",
+					KeepAlive: tt.keepAlive,
+				})
+			require.NoError(t, err)
+
+			ln, err := server.listen()
+			require.NoError(t, err)
+			defer ln.Close()
+
+			client, err := net.Dial("tcp", ln.Addr().String())
+			require.NoError(t, err)
+			defer client.Close()
+
+			conn, err := ln.Accept()
+			require.NoError(t, err)
+			defer conn.Close()
+
+			raw, err := conn.(*net.TCPConn).SyscallConn()
+			require.NoError(t, err)
+
+			var enabled, interval int
+			var sockErr error
+			require.NoError(t, raw.Control(func(fd uintptr) {
+				if enabled, sockErr = syscall.GetsockoptInt(int(fd), syscall.SOL_SOCKET, syscall.SO_KEEPALIVE); sockErr != nil {
+					return
+				}
+				interval, sockErr = syscall.GetsockoptInt(int(fd), syscall.IPPROTO_TCP, syscall.TCP_KEEPINTVL)
+			}))
+			require.NoError(t, sockErr)
+
+			assert.Equal(t, tt.enabled, enabled)
+			if tt.enabled == 1 {
+				assert.Equal(t, tt.interval, interval)
+			}
+		})
+	}
+}
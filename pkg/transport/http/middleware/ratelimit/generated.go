@@ -0,0 +1,29 @@
+// Code generated by @apexlang/codegen. DO NOT EDIT.
+
+package ratelimit
+
+import (
+	"github.com/nanobus/nanobus/pkg/transport/http/middleware"
+)
+
+// Limits the rate of requests to selected paths with a token bucket per path.
+// Requests over the limit are rejected with `429 Too Many Requests`. Paths that are
+// not listed are not limited.
+type RateLimitV0Config struct {
+	// The rate limits to apply. When several paths match a request, the longest one
+	// is used.
+	Limits []RateLimit `json:"limits" yaml:"limits" msgpack:"limits" mapstructure:"limits" validate:"dive"`
+}
+
+func RateLimitV0() (string, middleware.Loader) {
+	return "nanobus.transport.http.ratelimit/v0", RateLimitV0Loader
+}
+
+type RateLimit struct {
+	// The path prefix the limit applies to (e.g. `/greeter/`).
+	Path string `json:"path" yaml:"path" msgpack:"path" mapstructure:"path" validate:"required"`
+	// The number of requests allowed per second.
+	PerSecond uint32 `json:"perSecond" yaml:"perSecond" msgpack:"perSecond" mapstructure:"perSecond"`
+	// The number of requests allowed in a single burst. Defaults to `perSecond`.
+	Burst *uint32 `json:"burst,omitempty" yaml:"burst,omitempty" msgpack:"burst,omitempty" mapstructure:"burst"`
+}
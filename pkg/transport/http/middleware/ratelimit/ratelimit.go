@@ -0,0 +1,91 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//go:generate apex generate
+package ratelimit
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"sort"
+	"strings"
+
+	"golang.org/x/time/rate"
+
+	"github.com/nanobus/nanobus/pkg/config"
+	"github.com/nanobus/nanobus/pkg/errorz"
+	"github.com/nanobus/nanobus/pkg/resolve"
+	"github.com/nanobus/nanobus/pkg/transport/http/middleware"
+)
+
+type pathLimiter struct {
+	path    string
+	limiter *rate.Limiter
+}
+
+func RateLimitV0Loader(ctx context.Context, with interface{}, resolver resolve.ResolveAs) (middleware.Middleware, error) {
+	var c RateLimitV0Config
+	if err := config.Decode(with, &c); err != nil {
+		return nil, err
+	}
+
+	return RateLimitV0Handler(c.Limits)
+}
+
+func RateLimitV0Handler(limits []RateLimit) (middleware.Middleware, error) {
+	limiters := make([]pathLimiter, len(limits))
+	for i, l := range limits {
+		if l.PerSecond == 0 {
+			return nil, fmt.Errorf("rate limit for %q must allow at least one request per second", l.Path)
+		}
+		burst := l.PerSecond
+		if l.Burst != nil {
+			if *l.Burst == 0 {
+				return nil, fmt.Errorf("rate limit for %q must allow a burst of at least one request", l.Path)
+			}
+			burst = *l.Burst
+		}
+		limiters[i] = pathLimiter{
+			path:    l.Path,
+			limiter: rate.NewLimiter(rate.Limit(l.PerSecond), int(burst)),
+		}
+	}
+
+	// Longest paths first so the most specific limit wins.
+	sort.SliceStable(limiters, func(i, j int) bool {
+		return len(limiters[i].path) > len(limiters[j].path)
+	})
+
+	return func(h http.Handler) http.Handler {
+		return http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+			for _, l := range limiters {
+				if !strings.HasPrefix(r.URL.Path, l.path) {
+					continue
+				}
+				if !l.limiter.Allow() {
+					tooManyRequests(w, r)
+					return
+				}
+				break
+			}
+			h.ServeHTTP(w, r)
+		})
+	}, nil
+}
+
+func tooManyRequests(w http.ResponseWriter, r *http.Request) {
+	errz := errorz.New(errorz.ResourceExhausted, "rate limit exceeded")
+	errz.Path = r.RequestURI
+
+	w.Header().Set("Content-Type", "application/json")
+	w.Header().Set("Retry-After", "1")
+	w.WriteHeader(http.StatusTooManyRequests)
+	_ = json.NewEncoder(w).Encode(errz)
+}
@@ -0,0 +1,91 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package ratelimit_test
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/nanobus/nanobus/pkg/transport/http/middleware/ratelimit"
+)
+
+func TestRateLimit(t *testing.T) {
+	one := uint32(1)
+	m, err := ratelimit.RateLimitV0Handler([]ratelimit.RateLimit{
+		{Path: "/greeter/", PerSecond: 1, Burst: &one},
+		{Path: "/greeter/expensive", PerSecond: 1, Burst: &one},
+		{Path: "/reports/", PerSecond: 1, Burst: &one},
+	})
+	require.NoError(t, err)
+	h := m(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusOK)
+	}))
+
+	serve := func(path string) int {
+		w := httptest.NewRecorder()
+		h.ServeHTTP(w, httptest.NewRequest(http.MethodPost, path, nil))
+		return w.Code
+	}
+
+	// Only the first request within the burst passes.
+	allowed, rejected := 0, 0
+	for i := 0; i < 5; i++ {
+		switch serve("/greeter/hello") {
+		case http.StatusOK:
+			allowed++
+		case http.StatusTooManyRequests:
+			rejected++
+		}
+	}
+	assert.Equal(t, 1, allowed)
+	assert.Equal(t, 4, rejected)
+
+	// The longer path has its own bucket.
+	assert.Equal(t, http.StatusOK, serve("/greeter/expensive"))
+	assert.Equal(t, http.StatusTooManyRequests, serve("/greeter/expensive"))
+
+	// Unlisted paths are unlimited.
+	for i := 0; i < 5; i++ {
+		assert.Equal(t, http.StatusOK, serve("/other"))
+	}
+}
+
+func TestRateLimitResponse(t *testing.T) {
+	one := uint32(1)
+	m, err := ratelimit.RateLimitV0Handler([]ratelimit.RateLimit{
+		{Path: "/", PerSecond: 1, Burst: &one},
+	})
+	require.NoError(t, err)
+	h := m(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {}))
+
+	h.ServeHTTP(httptest.NewRecorder(), httptest.NewRequest(http.MethodGet, "/", nil))
+	w := httptest.NewRecorder()
+	h.ServeHTTP(w, httptest.NewRequest(http.MethodGet, "/", nil))
+
+	assert.Equal(t, http.StatusTooManyRequests, w.Code)
+	assert.Equal(t, "1", w.Header().Get("Retry-After"))
+	assert.Contains(t, w.Body.String(), `"code":"resource_exhausted"`)
+}
+
+func TestRateLimitInvalid(t *testing.T) {
+	_, err := ratelimit.RateLimitV0Handler([]ratelimit.RateLimit{
+		{Path: "/greeter/"},
+	})
+	assert.EqualError(t, err, `rate limit for "/greeter/" must allow at least one request per second`)
+
+	zero := uint32(0)
+	_, err = ratelimit.RateLimitV0Handler([]ratelimit.RateLimit{
+		{Path: "/greeter/", PerSecond: 10, Burst: &zero},
+	})
+	assert.EqualError(t, err, `rate limit for "/greeter/" must allow a burst of at least one request`)
+}
@@ -0,0 +1,19 @@
+// Code generated by @apexlang/codegen. DO NOT EDIT.
+
+package health
+
+import (
+	"github.com/nanobus/nanobus/pkg/transport/http/router"
+)
+
+// Serves a lightweight health check endpoint for load balancers and
+// orchestrators. `GET` and `HEAD` requests return `200 OK` without invoking any
+// application operation.
+type HealthV1Config struct {
+	// The path of the health check endpoint. Defaults to `/healthz`.
+	Path *string `json:"path,omitempty" yaml:"path,omitempty" msgpack:"path,omitempty" mapstructure:"path"`
+}
+
+func HealthV1() (string, router.Loader) {
+	return "nanobus.transport.http.health/v1", HealthV1Loader
+}
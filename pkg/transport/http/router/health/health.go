@@ -0,0 +1,62 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+//go:generate apex generate
+package health
+
+import (
+	"context"
+	"net/http"
+
+	"github.com/go-logr/logr"
+	"github.com/gorilla/mux"
+
+	"github.com/nanobus/nanobus/pkg/config"
+	"github.com/nanobus/nanobus/pkg/resolve"
+	"github.com/nanobus/nanobus/pkg/transport/http/router"
+)
+
+const defaultPath = "/healthz"
+
+func HealthV1Loader(ctx context.Context, with interface{}, resolver resolve.ResolveAs) (router.Router, error) {
+	c := HealthV1Config{}
+	if err := config.Decode(with, &c); err != nil {
+		return nil, err
+	}
+
+	var logger logr.Logger
+	if err := resolve.Resolve(resolver,
+		"system:logger", &logger); err != nil {
+		return nil, err
+	}
+
+	return NewV1(logger, c), nil
+}
+
+func NewV1(log logr.Logger, config HealthV1Config) router.Router {
+	return func(r *mux.Router, address string) error {
+		path := defaultPath
+		if config.Path != nil {
+			path = *config.Path
+		}
+		log.Info("Serving health check", "path", path)
+
+		r.HandleFunc(path, handler).Methods(http.MethodGet, http.MethodHead)
+
+		return nil
+	}
+}
+
+func handler(w http.ResponseWriter, r *http.Request) {
+	w.Header().Set("Content-Type", "text/plain")
+	w.Header().Set("Cache-Control", "no-store")
+	w.WriteHeader(http.StatusOK)
+	if r.Method != http.MethodHead {
+		_, _ = w.Write([]byte("OK"))
+	}
+}
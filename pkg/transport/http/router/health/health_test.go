@@ -0,0 +1,70 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package health_test
+
+import (
+	"net/http"
+	"net/http/httptest"
+	"testing"
+
+	"github.com/go-logr/logr"
+	"github.com/gorilla/mux"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/nanobus/nanobus/pkg/transport/http/router/health"
+)
+
+func TestHealth(t *testing.T) {
+	custom := "/status"
+	tests := map[string]struct {
+		config health.HealthV1Config
+		method string
+		path   string
+		status int
+		body   string
+	}{
+		"default path": {
+			method: http.MethodGet,
+			path:   "/healthz",
+			status: http.StatusOK,
+			body:   "OK",
+		},
+		"head": {
+			method: http.MethodHead,
+			path:   "/healthz",
+			status: http.StatusOK,
+		},
+		"custom path": {
+			config: health.HealthV1Config{Path: &custom},
+			method: http.MethodGet,
+			path:   "/status",
+			status: http.StatusOK,
+			body:   "OK",
+		},
+		"method not allowed": {
+			method: http.MethodPost,
+			path:   "/healthz",
+			status: http.StatusMethodNotAllowed,
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			r := mux.NewRouter()
+			require.NoError(t, health.NewV1(logr.Discard(), tt.config)(r, ":8080"))
+
+			w := httptest.NewRecorder()
+			r.ServeHTTP(w, httptest.NewRequest(tt.method, tt.path, nil))
+
+			assert.Equal(t, tt.status, w.Code)
+			assert.Equal(t, tt.body, w.Body.String())
+		})
+	}
+}
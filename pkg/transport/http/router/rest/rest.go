@@ -347,14 +347,14 @@ func (t *Rest) handler(h handler.Handler, isActor bool,
 		for _, filter := range t.filters {
 			var err error
 			if ctx, err = filter(ctx, r.Header); err != nil {
-				t.handleError(err, codec, r, w, http.StatusInternalServerError)
+				t.handleError(err, codec, r, w, errorz.Internal)
 				return
 			}
 		}
 
 		requestBytes, err := io.ReadAll(r.Body)
 		if err != nil {
-			t.handleError(err, codec, r, w, http.StatusInternalServerError)
+			t.handleError(err, codec, r, w, errorz.Internal)
 			return
 		}
 
@@ -362,13 +362,13 @@ func (t *Rest) handler(h handler.Handler, isActor bool,
 		if len(requestBytes) > 0 {
 			if bodyParamName == "" {
 				if err := codec.Decode(requestBytes, &input); err != nil {
-					t.handleError(err, codec, r, w, http.StatusInternalServerError)
+					t.handleError(err, codec, r, w, errorz.InvalidArgument)
 					return
 				}
 			} else {
 				var body interface{}
 				if err := codec.Decode(requestBytes, &body); err != nil {
-					t.handleError(err, codec, r, w, http.StatusInternalServerError)
+					t.handleError(err, codec, r, w, errorz.InvalidArgument)
 					return
 				}
 				input = map[string]interface{}{
@@ -395,7 +395,7 @@ func (t *Rest) handler(h handler.Handler, isActor bool,
 							for _, v := range parts {
 								converted, _, err = q.typeRef.Coalesce(v, false)
 								if err != nil {
-									t.handleError(err, codec, r, w, http.StatusBadRequest)
+									t.handleError(err, codec, r, w, errorz.InvalidArgument)
 									return
 								}
 								items = append(items, converted)
@@ -405,7 +405,7 @@ func (t *Rest) handler(h handler.Handler, isActor bool,
 					} else {
 						converted, _, err = q.typeRef.Coalesce(values[0], false)
 						if err != nil {
-							t.handleError(err, codec, r, w, http.StatusBadRequest)
+							t.handleError(err, codec, r, w, errorz.InvalidArgument)
 							return
 						}
 					}
@@ -447,9 +447,9 @@ func (t *Rest) handler(h handler.Handler, isActor bool,
 
 		response, err := t.invoker(ctx, h, id, input, transport.PerformAuthorization)
 		if err != nil {
-			code := http.StatusInternalServerError
+			code := errorz.Internal
 			if errors.Is(err, transport.ErrBadInput) {
-				code = http.StatusBadRequest
+				code = errorz.InvalidArgument
 			}
 			t.handleError(err, codec, r, w, code)
 			return
@@ -473,12 +473,12 @@ func (t *Rest) handler(h handler.Handler, isActor bool,
 			w.WriteHeader(resp.Status)
 			responseBytes, err := codec.Encode(response)
 			if err != nil {
-				t.handleError(err, codec, r, w, http.StatusInternalServerError)
+				t.handleError(err, codec, r, w, errorz.Internal)
 				return
 			}
 
 			if _, err := w.Write(responseBytes); err != nil {
-				t.handleError(err, codec, r, w, http.StatusInternalServerError)
+				t.handleError(err, codec, r, w, errorz.Internal)
 				return
 			}
 
@@ -492,10 +492,17 @@ func (t *Rest) handler(h handler.Handler, isActor bool,
 	}
 }
 
-func (t *Rest) handleError(err error, codec channel.Codec, req *http.Request, w http.ResponseWriter, status int) {
+// handleError writes err as the response. Errors that are not already
+// an *errorz.Error are reported with code, or passed to the error
+// resolver when code is Internal.
+func (t *Rest) handleError(err error, codec channel.Codec, req *http.Request, w http.ResponseWriter, code errorz.ErrCode) {
 	var errz *errorz.Error
 	if !errors.As(err, &errz) {
-		errz = t.errorResolver(err)
+		if code == errorz.Internal {
+			errz = t.errorResolver(err)
+		} else {
+			errz = errorz.Wrap(err, code, err.Error())
+		}
 	}
 	errz.Path = req.RequestURI
 
@@ -0,0 +1,94 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package rest_test
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/go-logr/logr"
+	"github.com/gorilla/mux"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/otel/trace"
+
+	json_codec "github.com/nanobus/nanobus/pkg/channel/codecs/json"
+	"github.com/nanobus/nanobus/pkg/errorz"
+	"github.com/nanobus/nanobus/pkg/handler"
+	"github.com/nanobus/nanobus/pkg/spec"
+	"github.com/nanobus/nanobus/pkg/transport"
+	"github.com/nanobus/nanobus/pkg/transport/http/router/rest"
+)
+
+func greeterNamespaces() spec.Namespaces {
+	ns := spec.NewNamespace("greeter.v1")
+	ns.AddService(spec.NewService("Greeter", "").
+		AddAnnotations(
+			spec.NewAnnotation("service"),
+			spec.NewAnnotation("path").AddArgument(spec.NewArgument("value", "/greeter"))).
+		AddOperation(spec.NewOperation("sayHello", "", true, spec.NewType(ns, "SayHelloArgs", ""), nil).
+			AddAnnotations(
+				spec.NewAnnotation("POST"),
+				spec.NewAnnotation("path").AddArgument(spec.NewArgument("value", "/hello")))))
+
+	return spec.Namespaces{}.AddNamespace(ns)
+}
+
+func TestErrorStatus(t *testing.T) {
+	tests := map[string]struct {
+		body      string
+		invokeErr error
+		status    int
+	}{
+		"malformed body": {
+			body:   `{"name":`,
+			status: http.StatusBadRequest,
+		},
+		"bad input": {
+			body:      `{"name":"World"}`,
+			invokeErr: fmt.Errorf("%w: name is required", transport.ErrBadInput),
+			status:    http.StatusBadRequest,
+		},
+		"internal": {
+			body:      `{"name":"World"}`,
+			invokeErr: errors.New("boom"),
+			status:    http.StatusInternalServerError,
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			invoker := func(ctx context.Context, h handler.Handler, id string, input interface{}, authorization transport.Authorization) (interface{}, error) {
+				return nil, tt.invokeErr
+			}
+			errorResolver := func(err error) *errorz.Error {
+				return errorz.Wrap(err, errorz.Internal, err.Error())
+			}
+
+			route, err := rest.NewV1(logr.Discard(), trace.NewNoopTracerProvider().Tracer(""),
+				rest.RestV1Config{}, greeterNamespaces(), invoker, errorResolver,
+				rest.WithCodecs(json_codec.New()))
+			require.NoError(t, err)
+			r := mux.NewRouter()
+			require.NoError(t, route(r, ""))
+
+			req := httptest.NewRequest(http.MethodPost, "/greeter/hello", strings.NewReader(tt.body))
+			req.Header.Set("Content-Type", "application/json")
+			rec := httptest.NewRecorder()
+			r.ServeHTTP(rec, req)
+
+			assert.Equal(t, tt.status, rec.Code)
+		})
+	}
+}
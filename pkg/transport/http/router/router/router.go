@@ -138,21 +138,21 @@ func (t *Router) handler(h handler.Handler, raw bool, desiredCodec channel.Codec
 		for _, filter := range t.filters {
 			var err error
 			if ctx, err = filter(ctx, r.Header); err != nil {
-				t.handleError(err, codec, r, w, http.StatusInternalServerError)
+				t.handleError(err, codec, r, w, errorz.Internal)
 				return
 			}
 		}
 
 		requestBytes, err := io.ReadAll(r.Body)
 		if err != nil {
-			t.handleError(err, codec, r, w, http.StatusInternalServerError)
+			t.handleError(err, codec, r, w, errorz.Internal)
 			return
 		}
 
 		var body interface{}
 		if len(requestBytes) > 0 {
 			if err := codec.Decode(requestBytes, &body); err != nil {
-				t.handleError(err, codec, r, w, http.StatusInternalServerError)
+				t.handleError(err, codec, r, w, errorz.InvalidArgument)
 				return
 			}
 		}
@@ -194,7 +194,7 @@ func (t *Router) handler(h handler.Handler, raw bool, desiredCodec channel.Codec
 			} else {
 				targetBytes, err := desiredCodec.Encode(body)
 				if err != nil {
-					t.handleError(err, codec, r, w, http.StatusInternalServerError)
+					t.handleError(err, codec, r, w, errorz.Internal)
 					return
 				}
 				input["data_bytes"] = targetBytes
@@ -203,9 +203,9 @@ func (t *Router) handler(h handler.Handler, raw bool, desiredCodec channel.Codec
 
 		response, err := t.invoker(ctx, h, id, input, transport.PerformAuthorization)
 		if err != nil {
-			code := http.StatusInternalServerError
+			code := errorz.Internal
 			if errors.Is(err, transport.ErrBadInput) {
-				code = http.StatusBadRequest
+				code = errorz.InvalidArgument
 			}
 			t.handleError(err, codec, r, w, code)
 			return
@@ -229,7 +229,7 @@ func (t *Router) handler(h handler.Handler, raw bool, desiredCodec channel.Codec
 			w.WriteHeader(resp.Status)
 			responseBytes, err := codec.Encode(response)
 			if err != nil {
-				t.handleError(err, codec, r, w, http.StatusInternalServerError)
+				t.handleError(err, codec, r, w, errorz.Internal)
 				return
 			}
 			if _, err := w.Write(responseBytes); err != nil {
@@ -245,10 +245,17 @@ func (t *Router) handler(h handler.Handler, raw bool, desiredCodec channel.Codec
 	}
 }
 
-func (t *Router) handleError(err error, codec channel.Codec, req *http.Request, w http.ResponseWriter, status int) {
+// handleError writes err as the response. Errors that are not already
+// an *errorz.Error are reported with code, or passed to the error
+// resolver when code is Internal.
+func (t *Router) handleError(err error, codec channel.Codec, req *http.Request, w http.ResponseWriter, code errorz.ErrCode) {
 	var errz *errorz.Error
 	if !errors.As(err, &errz) {
-		errz = t.errorResolver(err)
+		if code == errorz.Internal {
+			errz = t.errorResolver(err)
+		} else {
+			errz = errorz.Wrap(err, code, err.Error())
+		}
 	}
 	errz.Path = req.RequestURI
 
@@ -0,0 +1,84 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package router_test
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/http/httptest"
+	"strings"
+	"testing"
+
+	"github.com/go-logr/logr"
+	"github.com/gorilla/mux"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/nanobus/nanobus/pkg/channel"
+	json_codec "github.com/nanobus/nanobus/pkg/channel/codecs/json"
+	"github.com/nanobus/nanobus/pkg/errorz"
+	"github.com/nanobus/nanobus/pkg/handler"
+	"github.com/nanobus/nanobus/pkg/transport"
+	"github.com/nanobus/nanobus/pkg/transport/http/router/router"
+)
+
+func TestErrorStatus(t *testing.T) {
+	tests := map[string]struct {
+		body      string
+		invokeErr error
+		status    int
+	}{
+		"malformed body": {
+			body:   `{"name":`,
+			status: http.StatusBadRequest,
+		},
+		"bad input": {
+			body:      `{"name":"World"}`,
+			invokeErr: fmt.Errorf("%w: name is required", transport.ErrBadInput),
+			status:    http.StatusBadRequest,
+		},
+		"internal": {
+			body:      `{"name":"World"}`,
+			invokeErr: errors.New("boom"),
+			status:    http.StatusInternalServerError,
+		},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			invoker := func(ctx context.Context, h handler.Handler, id string, input interface{}, authorization transport.Authorization) (interface{}, error) {
+				return nil, tt.invokeErr
+			}
+			errorResolver := func(err error) *errorz.Error {
+				return errorz.Wrap(err, errorz.Internal, err.Error())
+			}
+			codec := json_codec.New()
+
+			r := mux.NewRouter()
+			require.NoError(t, router.NewV1(logr.Discard(), invoker,
+				channel.Codecs{codec.ContentType(): codec}, errorResolver,
+				router.RouterV1Config{
+					Routes: []router.AddRoute{{
+						Method:  http.MethodPost,
+						URI:     "/greet",
+						Handler: handler.Handler{Interface: "greeter.v1.Greeter", Operation: "sayHello"},
+					}},
+				}, nil)(r, ""))
+
+			req := httptest.NewRequest(http.MethodPost, "/greet", strings.NewReader(tt.body))
+			req.Header.Set("Content-Type", "application/json")
+			rec := httptest.NewRecorder()
+			r.ServeHTTP(rec, req)
+
+			assert.Equal(t, tt.status, rec.Code)
+		})
+	}
+}
@@ -10,9 +10,12 @@ package http
 
 import (
 	"context"
+	"crypto/tls"
 	"errors"
 	"net"
 	"net/http"
+	"sync"
+	"time"
 
 	"github.com/go-logr/logr"
 	"github.com/gorilla/handlers"
@@ -28,11 +31,17 @@ import (
 )
 
 type Server struct {
-	log     logr.Logger
-	tracer  trace.Tracer
-	address string
-	handler http.Handler
-	ln      net.Listener
+	log          logr.Logger
+	tracer       trace.Tracer
+	address      string
+	handler      http.Handler
+	drainTimeout time.Duration
+	keepAlive    time.Duration
+	idleTimeout  time.Duration
+	tlsConfig    *tls.Config
+
+	mu  sync.Mutex
+	srv *http.Server
 }
 
 type optionsHolder struct {
@@ -124,30 +133,98 @@ func NewServer(log logr.Logger, tracer trace.Tracer, config HttpServerV1Config,
 		}
 	}
 
+	drainTimeout := transport.DefaultDrainTimeout
+	if config.DrainTimeout != nil {
+		drainTimeout = *config.DrainTimeout
+	}
+
+	var keepAlive, idleTimeout time.Duration
+	if config.KeepAlive != nil {
+		keepAlive = *config.KeepAlive
+	}
+	if config.IdleTimeout != nil {
+		idleTimeout = *config.IdleTimeout
+	}
+
+	var tlsConfig *tls.Config
+	if config.TLS != nil {
+		var err error
+		if tlsConfig, err = loadTLSConfig(config.TLS); err != nil {
+			return nil, err
+		}
+	}
+
 	return &Server{
-		log:     log,
-		tracer:  tracer,
-		address: config.Address,
-		handler: handler,
+		log:          log,
+		tracer:       tracer,
+		address:      config.Address,
+		handler:      handler,
+		drainTimeout: drainTimeout,
+		keepAlive:    keepAlive,
+		idleTimeout:  idleTimeout,
+		tlsConfig:    tlsConfig,
 	}, nil
 }
 
 func (t *Server) Listen() error {
-	ln, err := net.Listen("tcp", t.address)
+	ln, err := t.listen()
 	if err != nil {
 		return err
 	}
-	t.ln = ln
-	t.log.Info("HTTP server listening", "address", t.address)
 
-	handler := otelhttp.NewHandler(t.handler, "http")
-	return http.Serve(ln, handler)
+	srv := &http.Server{
+		Handler:     otelhttp.NewHandler(t.handler, "http"),
+		IdleTimeout: t.idleTimeout,
+	}
+	t.mu.Lock()
+	t.srv = srv
+	t.mu.Unlock()
+
+	if err := srv.Serve(ln); !errors.Is(err, http.ErrServerClosed) {
+		return err
+	}
+
+	return nil
+}
+
+// listen announces on the server address. Accepted TCP connections use
+// the configured keep-alive period, and a negative period disables
+// keep-alive probes.
+func (t *Server) listen() (net.Listener, error) {
+	ln, err := transport.ListenConfig(net.ListenConfig{KeepAlive: t.keepAlive}, t.address)
+	if err != nil {
+		return nil, err
+	}
+	if t.tlsConfig != nil {
+		ln = tls.NewListener(ln, t.tlsConfig)
+		t.log.Info("HTTPS server listening", "address", t.address)
+	} else {
+		t.log.Info("HTTP server listening", "address", t.address)
+	}
+
+	return ln, nil
 }
 
+// Close stops accepting connections and waits up to the drain timeout
+// for in-flight requests to complete. Connections still active after
+// the timeout are closed forcibly.
 func (t *Server) Close() (err error) {
-	if t.ln != nil {
-		err = t.ln.Close()
-		t.ln = nil
+	t.mu.Lock()
+	srv := t.srv
+	t.srv = nil
+	t.mu.Unlock()
+
+	if srv == nil {
+		return nil
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), t.drainTimeout)
+	defer cancel()
+
+	if err = srv.Shutdown(ctx); errors.Is(err, context.DeadlineExceeded) {
+		t.log.Info("HTTP server drain timeout exceeded, closing remaining connections",
+			"timeout", t.drainTimeout)
+		err = srv.Close()
 	}
 
 	return err
@@ -0,0 +1,158 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package http_test
+
+import (
+	"bufio"
+	"fmt"
+	"io"
+	"net"
+	"net/http"
+	"testing"
+	"time"
+
+	"github.com/go-logr/logr"
+	"github.com/gorilla/mux"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/otel/trace"
+
+	transport_http "github.com/nanobus/nanobus/pkg/transport/http"
+)
+
+func TestServerKeepAlive(t *testing.T) {
+	keepAlive := time.Second
+	idleTimeout := 5 * time.Second
+	request, _ := dialServer(t, transport_http.HttpServerV1Config{
+		Address:     freeAddress(t),
+		KeepAlive:   &keepAlive,
+		IdleTimeout: &idleTimeout,
+	})
+
+	// No routers are configured, so every completed request is a 404.
+	assert.Equal(t, http.StatusNotFound, request())
+
+	// Idling past the keep-alive interval, but not the idle timeout,
+	// keeps the connection usable.
+	time.Sleep(keepAlive + keepAlive/2)
+	assert.Equal(t, http.StatusNotFound, request())
+}
+
+func TestServerIdleTimeout(t *testing.T) {
+	idleTimeout := 500 * time.Millisecond
+	request, reader := dialServer(t, transport_http.HttpServerV1Config{
+		Address:     freeAddress(t),
+		IdleTimeout: &idleTimeout,
+	})
+
+	assert.Equal(t, http.StatusNotFound, request())
+
+	// Idling for less than the idle timeout keeps the connection usable.
+	time.Sleep(idleTimeout / 5)
+	assert.Equal(t, http.StatusNotFound, request())
+
+	// Idling past the idle timeout closes the connection.
+	time.Sleep(2 * idleTimeout)
+	_, err := reader.ReadByte()
+	assert.ErrorIs(t, err, io.EOF)
+}
+
+// dialServer starts a server with config and opens a single connection
+// to it. request sends a GET over that connection and returns the
+// response status, so tests control when the connection is reused.
+func dialServer(t *testing.T, config transport_http.HttpServerV1Config) (request func() int, reader *bufio.Reader) {
+	server, err := transport_http.NewServer(logr.Discard(), trace.NewNoopTracerProvider().Tracer(""), config)
+	require.NoError(t, err)
+
+	done := make(chan error, 1)
+	go func() { done <- server.Listen() }()
+	t.Cleanup(func() {
+		require.NoError(t, server.Close())
+		require.NoError(t, <-done)
+	})
+
+	var conn net.Conn
+	require.Eventually(t, func() bool {
+		conn, err = net.Dial("tcp", config.Address)
+		return err == nil
+	}, 5*time.Second, 10*time.Millisecond)
+	t.Cleanup(func() { conn.Close() })
+
+	reader = bufio.NewReader(conn)
+	request = func() int {
+		_, err := fmt.Fprintf(conn, "GET / HTTP/1.1\r\nHost: %s\r\n\r\n", config.Address)
+		require.NoError(t, err)
+		resp, err := http.ReadResponse(reader, nil)
+		require.NoError(t, err)
+		defer resp.Body.Close()
+		_, _ = io.Copy(io.Discard, resp.Body)
+		return resp.StatusCode
+	}
+
+	return request, reader
+}
+
+func TestServerDrainTimeout(t *testing.T) {
+	drainTimeout := 100 * time.Millisecond
+	started := make(chan struct{})
+	release := make(chan struct{})
+	defer close(release)
+
+	address := freeAddress(t)
+	server, err := transport_http.NewServer(logr.Discard(), trace.NewNoopTracerProvider().Tracer(""),
+		transport_http.HttpServerV1Config{
+			Address:      address,
+			DrainTimeout: &drainTimeout,
+		},
+		transport_http.WithRoutes(func(r *mux.Router, address string) error {
+			r.HandleFunc("/block", func(w http.ResponseWriter, r *http.Request) {
+				close(started)
+				<-release
+			})
+			return nil
+		}))
+	require.NoError(t, err)
+
+	done := make(chan error, 1)
+	go func() { done <- server.Listen() }()
+
+	require.Eventually(t, func() bool {
+		conn, err := net.Dial("tcp", address)
+		if err != nil {
+			return false
+		}
+		conn.Close()
+		return true
+	}, 5*time.Second, 10*time.Millisecond)
+
+	clientErr := make(chan error, 1)
+	go func() {
+		resp, err := http.Get("http://" + address + "/block")
+		if err == nil {
+			resp.Body.Close()
+		}
+		clientErr <- err
+	}()
+	<-started
+
+	start := time.Now()
+	require.NoError(t, server.Close())
+	elapsed := time.Since(start)
+	assert.GreaterOrEqual(t, elapsed, drainTimeout)
+	assert.Less(t, elapsed, drainTimeout+time.Second)
+
+	// The handler never returned, so the connection was closed forcibly.
+	select {
+	case err := <-clientErr:
+		assert.Error(t, err)
+	case <-time.After(5 * time.Second):
+		t.Fatal("client request was not closed")
+	}
+	require.NoError(t, <-done)
+}
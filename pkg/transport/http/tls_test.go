@@ -0,0 +1,251 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package http_test
+
+import (
+	"crypto/ecdsa"
+	"crypto/elliptic"
+	"crypto/rand"
+	"crypto/tls"
+	"crypto/x509"
+	"crypto/x509/pkix"
+	"encoding/pem"
+	"fmt"
+	"math/big"
+	"net"
+	"net/http"
+	"os"
+	"path/filepath"
+	"testing"
+	"time"
+
+	"github.com/go-logr/logr"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/otel/trace"
+
+	transport_http "github.com/nanobus/nanobus/pkg/transport/http"
+)
+
+func TestServerTLS(t *testing.T) {
+	dir := t.TempDir()
+	certFile, keyFile, cert := writeSelfSigned(t, dir)
+
+	address := freeAddress(t)
+	server, err := transport_http.NewServer(logr.Discard(), trace.NewNoopTracerProvider().Tracer(""),
+		transport_http.HttpServerV1Config{
+			Address: address,
+			TLS: &transport_http.TLS{
+				CertFile: certFile,
+				KeyFile:  keyFile,
+			},
+		})
+	require.NoError(t, err)
+
+	done := make(chan error, 1)
+	go func() { done <- server.Listen() }()
+	defer func() {
+		require.NoError(t, server.Close())
+		require.NoError(t, <-done)
+	}()
+
+	pool := x509.NewCertPool()
+	pool.AddCert(cert)
+	client := http.Client{
+		Transport: &http.Transport{
+			TLSClientConfig:   &tls.Config{RootCAs: pool},
+			ForceAttemptHTTP2: true,
+		},
+	}
+
+	var resp *http.Response
+	require.Eventually(t, func() bool {
+		resp, err = client.Get("https://" + address + "/")
+		return err == nil
+	}, 5*time.Second, 10*time.Millisecond)
+	defer resp.Body.Close()
+
+	// No routers are configured, so a completed request proves the handshake.
+	assert.Equal(t, http.StatusNotFound, resp.StatusCode)
+	assert.Equal(t, 2, resp.ProtoMajor, "HTTP/2 was not negotiated")
+}
+
+func TestServerTLSMissingCertificate(t *testing.T) {
+	_, err := transport_http.NewServer(logr.Discard(), trace.NewNoopTracerProvider().Tracer(""),
+		transport_http.HttpServerV1Config{
+			Address: ":0",
+			TLS: &transport_http.TLS{
+				CertFile: "missing.crt",
+				KeyFile:  "missing.key",
+			},
+		})
+	assert.ErrorContains(t, err, "could not load TLS certificate")
+}
+
+func TestServerMutualTLS(t *testing.T) {
+	dir := t.TempDir()
+	certFile, keyFile, cert := writeSelfSigned(t, dir)
+	caFile, clientCert := writeClientCA(t, dir)
+
+	address := freeAddress(t)
+	server, err := transport_http.NewServer(logr.Discard(), trace.NewNoopTracerProvider().Tracer(""),
+		transport_http.HttpServerV1Config{
+			Address: address,
+			TLS: &transport_http.TLS{
+				CertFile:     certFile,
+				KeyFile:      keyFile,
+				ClientCAFile: &caFile,
+			},
+		})
+	require.NoError(t, err)
+
+	done := make(chan error, 1)
+	go func() { done <- server.Listen() }()
+	defer func() {
+		require.NoError(t, server.Close())
+		require.NoError(t, <-done)
+	}()
+
+	pool := x509.NewCertPool()
+	pool.AddCert(cert)
+	newClient := func(certs ...tls.Certificate) *http.Client {
+		return &http.Client{
+			Transport: &http.Transport{
+				TLSClientConfig: &tls.Config{
+					RootCAs:      pool,
+					Certificates: certs,
+				},
+			},
+		}
+	}
+
+	require.Eventually(t, func() bool {
+		conn, err := net.Dial("tcp", address)
+		if err != nil {
+			return false
+		}
+		conn.Close()
+		return true
+	}, 5*time.Second, 10*time.Millisecond)
+
+	// Without a client certificate the handshake is rejected.
+	_, err = newClient().Get("https://" + address + "/")
+	assert.Error(t, err)
+
+	// A certificate signed by the client CA is accepted.
+	resp, err := newClient(clientCert).Get("https://" + address + "/")
+	require.NoError(t, err)
+	defer resp.Body.Close()
+	assert.Equal(t, http.StatusNotFound, resp.StatusCode)
+}
+
+func TestServerTLSEmptyClientCA(t *testing.T) {
+	dir := t.TempDir()
+	certFile, keyFile, _ := writeSelfSigned(t, dir)
+	caFile := filepath.Join(dir, "ca.crt")
+	require.NoError(t, os.WriteFile(caFile, []byte("not a certificate"), 0600))
+
+	_, err := transport_http.NewServer(logr.Discard(), trace.NewNoopTracerProvider().Tracer(""),
+		transport_http.HttpServerV1Config{
+			Address: ":0",
+			TLS: &transport_http.TLS{
+				CertFile:     certFile,
+				KeyFile:      keyFile,
+				ClientCAFile: &caFile,
+			},
+		})
+	assert.EqualError(t, err, fmt.Sprintf("no certificates found in client CA file %q", caFile))
+}
+
+func writeSelfSigned(t *testing.T, dir string) (certFile, keyFile string, cert *x509.Certificate) {
+	t.Helper()
+
+	key, err := ecdsa.GenerateKey(elliptic.P256(), rand.Reader)
+	require.NoError(t, err)
+
+	template := x509.Certificate{
+		SerialNumber: big.NewInt(1),
+		Subject:      pkix.Name{CommonName: "localhost"},
+		IPAddresses:  []net.IP{net.ParseIP("127.0.0.1")},
+		NotBefore:    time.Now().Add(-time.Hour),
+		NotAfter:     time.Now().Add(time.Hour),
+		KeyUsage:     x509.KeyUsageDigitalSignature,
+		ExtKeyUsage:  []x509.ExtKeyUsage{x509.ExtKeyUsageServerAuth},
+	}
+	der, err := x509.CreateCertificate(rand.Reader, &template, &template, &key.PublicKey, key)
+	require.NoError(t, err)
+	cert, err = x509.ParseCertificate(der)
+	require.NoError(t, err)
+
+	keyDER, err := x509.MarshalECPrivateKey(key)
+	require.NoError(t, err)
+
+	certFile = filepath.Join(dir, "server.crt")
+	keyFile = filepath.Join(dir, "server.key")
+	require.NoError(t, os.WriteFile(certFile,
+		pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: der}), 0600))
+	require.NoError(t, os.WriteFile(keyFile,
+		pem.EncodeToMemory(&pem.Block{Type: "EC PRIVATE KEY", Bytes: keyDER}), 0600))
+
+	return certFile, keyFile, cert
+}
+
+// writeClientCA writes a CA certificate to dir and returns its path
+// together with a client certificate signed by it.
+func writeClientCA(t *testing.T, dir string) (caFile string, clientCert tls.Certificate) {
+	t.Helper()
+
+	caKey, err := ecdsa.GenerateKey(elliptic.P256(), rand.Reader)
+	require.NoError(t, err)
+	caTemplate := x509.Certificate{
+		SerialNumber:          big.NewInt(2),
+		Subject:               pkix.Name{CommonName: "Test Client CA"},
+		NotBefore:             time.Now().Add(-time.Hour),
+		NotAfter:              time.Now().Add(time.Hour),
+		KeyUsage:              x509.KeyUsageCertSign | x509.KeyUsageDigitalSignature,
+		BasicConstraintsValid: true,
+		IsCA:                  true,
+	}
+	caDER, err := x509.CreateCertificate(rand.Reader, &caTemplate, &caTemplate, &caKey.PublicKey, caKey)
+	require.NoError(t, err)
+	ca, err := x509.ParseCertificate(caDER)
+	require.NoError(t, err)
+
+	clientKey, err := ecdsa.GenerateKey(elliptic.P256(), rand.Reader)
+	require.NoError(t, err)
+	clientTemplate := x509.Certificate{
+		SerialNumber: big.NewInt(3),
+		Subject:      pkix.Name{CommonName: "client"},
+		NotBefore:    time.Now().Add(-time.Hour),
+		NotAfter:     time.Now().Add(time.Hour),
+		KeyUsage:     x509.KeyUsageDigitalSignature,
+		ExtKeyUsage:  []x509.ExtKeyUsage{x509.ExtKeyUsageClientAuth},
+	}
+	clientDER, err := x509.CreateCertificate(rand.Reader, &clientTemplate, ca, &clientKey.PublicKey, caKey)
+	require.NoError(t, err)
+
+	caFile = filepath.Join(dir, "ca.crt")
+	require.NoError(t, os.WriteFile(caFile,
+		pem.EncodeToMemory(&pem.Block{Type: "CERTIFICATE", Bytes: caDER}), 0600))
+
+	return caFile, tls.Certificate{
+		Certificate: [][]byte{clientDER},
+		PrivateKey:  clientKey,
+	}
+}
+
+func freeAddress(t *testing.T) string {
+	t.Helper()
+
+	ln, err := net.Listen("tcp", "127.0.0.1:0")
+	require.NoError(t, err)
+	defer ln.Close()
+
+	return ln.Addr().String()
+}
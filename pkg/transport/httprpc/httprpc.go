@@ -13,11 +13,13 @@ import (
 	"errors"
 	"fmt"
 	"io"
-	"net"
 	"net/http"
+	"sync"
+	"time"
 
 	"github.com/go-logr/logr"
 	"github.com/gorilla/mux"
+	"go.opentelemetry.io/contrib/instrumentation/net/http/otelhttp"
 
 	"github.com/nanobus/nanobus/pkg/channel"
 	"github.com/nanobus/nanobus/pkg/config"
@@ -37,12 +39,18 @@ type HTTPRPC struct {
 	errorResolver errorz.Resolver
 	codecs        map[string]channel.Codec
 	filters       []filter.Filter
-	ln            net.Listener
+	drainTimeout  time.Duration
+	maxBodySize   int64
+
+	mu  sync.Mutex
+	srv *http.Server
 }
 
 type optionsHolder struct {
-	codecs  []channel.Codec
-	filters []filter.Filter
+	codecs       []channel.Codec
+	filters      []filter.Filter
+	drainTimeout time.Duration
+	maxBodySize  int64
 }
 
 var (
@@ -64,8 +72,25 @@ func WithFilters(filters ...filter.Filter) Option {
 	}
 }
 
+// WithDrainTimeout sets how long Close waits for in-flight requests.
+func WithDrainTimeout(drainTimeout time.Duration) Option {
+	return func(opts *optionsHolder) {
+		opts.drainTimeout = drainTimeout
+	}
+}
+
+// WithMaxBodySize limits request bodies to maxBodySize bytes. Zero
+// means no limit.
+func WithMaxBodySize(maxBodySize int64) Option {
+	return func(opts *optionsHolder) {
+		opts.maxBodySize = maxBodySize
+	}
+}
+
 type Configuration struct {
-	Address string `mapstructure:"address" validate:"required"`
+	Address      string        `mapstructure:"address" validate:"required"`
+	DrainTimeout time.Duration `mapstructure:"drainTimeout"`
+	MaxBodySize  int64         `mapstructure:"maxBodySize"`
 }
 
 func Load() (string, transport.Loader) {
@@ -91,18 +116,24 @@ func Loader(ctx context.Context, with interface{}, resolver resolve.ResolveAs) (
 		return nil, err
 	}
 
-	var c Configuration
+	c := Configuration{
+		DrainTimeout: transport.DefaultDrainTimeout,
+	}
 	if err := config.Decode(with, &c); err != nil {
 		return nil, err
 	}
 
 	return New(log, c.Address, namespaces, transportInvoker, errorResolver,
 		WithFilters(filters...),
-		WithCodecs(jsoncodec, msgpackcodec))
+		WithCodecs(jsoncodec, msgpackcodec),
+		WithDrainTimeout(c.DrainTimeout),
+		WithMaxBodySize(c.MaxBodySize))
 }
 
 func New(log logr.Logger, address string, namespaces spec.Namespaces, invoker transport.Invoker, errorResolver errorz.Resolver, options ...Option) (transport.Transport, error) {
-	var opts optionsHolder
+	opts := optionsHolder{
+		drainTimeout: transport.DefaultDrainTimeout,
+	}
 
 	for _, opt := range options {
 		opt(&opts)
@@ -120,6 +151,8 @@ func New(log logr.Logger, address string, namespaces spec.Namespaces, invoker tr
 		errorResolver: errorResolver,
 		codecs:        codecMap,
 		filters:       opts.filters,
+		drainTimeout:  opts.drainTimeout,
+		maxBodySize:   opts.maxBodySize,
 	}, nil
 }
 
@@ -128,20 +161,45 @@ func (t *HTTPRPC) Listen() error {
 	r.HandleFunc("/{interface}/{operation}", t.handler).Methods("POST")
 	r.HandleFunc("/{interface}/{id}/{operation}", t.handler).Methods("POST")
 	r.Use(mux.CORSMethodMiddleware(r))
-	ln, err := net.Listen("tcp", t.address)
+	ln, err := transport.Listen(t.address)
 	if err != nil {
 		return err
 	}
-	t.ln = ln
 	t.log.Info("HTTP RPC server listening", "address", t.address)
 
-	return http.Serve(ln, r)
+	srv := &http.Server{
+		Handler: otelhttp.NewHandler(r, "httprpc"),
+	}
+	t.mu.Lock()
+	t.srv = srv
+	t.mu.Unlock()
+
+	if err := srv.Serve(ln); !errors.Is(err, http.ErrServerClosed) {
+		return err
+	}
+
+	return nil
 }
 
+// Close stops accepting connections and waits up to the drain timeout
+// for in-flight requests to complete before closing the rest.
 func (t *HTTPRPC) Close() (err error) {
-	if t.ln != nil {
-		err = t.ln.Close()
-		t.ln = nil
+	t.mu.Lock()
+	srv := t.srv
+	t.srv = nil
+	t.mu.Unlock()
+
+	if srv == nil {
+		return nil
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), t.drainTimeout)
+	defer cancel()
+
+	if err = srv.Shutdown(ctx); errors.Is(err, context.DeadlineExceeded) {
+		t.log.Info("HTTP RPC server drain timeout exceeded, closing remaining connections",
+			"timeout", t.drainTimeout)
+		err = srv.Close()
 	}
 
 	return err
@@ -169,28 +227,39 @@ func (t *HTTPRPC) handler(w http.ResponseWriter, r *http.Request) {
 
 	var h handler.Handler
 	if err := h.FromString(operation); err != nil {
-		t.handleError(err, codec, r, w, http.StatusInternalServerError)
+		t.handleError(err, codec, r, w, errorz.InvalidArgument)
 		return
 	}
 
 	for _, filter := range t.filters {
 		var err error
 		if ctx, err = filter(ctx, r.Header); err != nil {
-			t.handleError(err, codec, r, w, http.StatusInternalServerError)
+			t.handleError(err, codec, r, w, errorz.Internal)
 			return
 		}
 	}
 
-	requestBytes, err := io.ReadAll(r.Body)
+	body := r.Body
+	if t.maxBodySize > 0 {
+		body = http.MaxBytesReader(w, r.Body, t.maxBodySize)
+	}
+	requestBytes, err := io.ReadAll(body)
 	if err != nil {
-		t.handleError(err, codec, r, w, http.StatusInternalServerError)
+		var maxBytesErr *http.MaxBytesError
+		if errors.As(err, &maxBytesErr) {
+			errz := errorz.Wrap(err, errorz.ResourceExhausted,
+				fmt.Sprintf("request body exceeds the limit of %d bytes", maxBytesErr.Limit))
+			errz.Status = http.StatusRequestEntityTooLarge
+			err = errz
+		}
+		t.handleError(err, codec, r, w, errorz.Internal)
 		return
 	}
 
 	var input interface{}
 	if len(requestBytes) > 0 {
 		if err := codec.Decode(requestBytes, &input); err != nil {
-			t.handleError(err, codec, r, w, http.StatusInternalServerError)
+			t.handleError(err, codec, r, w, errorz.InvalidArgument)
 			return
 		}
 	} else {
@@ -199,9 +268,9 @@ func (t *HTTPRPC) handler(w http.ResponseWriter, r *http.Request) {
 
 	response, err := t.invoker(ctx, h, id, input, transport.PerformAuthorization)
 	if err != nil {
-		code := http.StatusInternalServerError
+		code := errorz.Internal
 		if errors.Is(err, transport.ErrBadInput) {
-			code = http.StatusBadRequest
+			code = errorz.InvalidArgument
 		}
 		t.handleError(err, codec, r, w, code)
 		return
@@ -210,7 +279,7 @@ func (t *HTTPRPC) handler(w http.ResponseWriter, r *http.Request) {
 	w.Header().Set("Content-Type", codec.ContentType())
 	responseBytes, err := codec.Encode(response)
 	if err != nil {
-		t.handleError(err, codec, r, w, http.StatusInternalServerError)
+		t.handleError(err, codec, r, w, errorz.Internal)
 		return
 	}
 
@@ -219,10 +288,17 @@ func (t *HTTPRPC) handler(w http.ResponseWriter, r *http.Request) {
 	}
 }
 
-func (t *HTTPRPC) handleError(err error, codec channel.Codec, req *http.Request, w http.ResponseWriter, status int) {
+// handleError writes err as the response. Errors that are not already
+// an *errorz.Error are reported with code, or passed to the error
+// resolver when code is Internal.
+func (t *HTTPRPC) handleError(err error, codec channel.Codec, req *http.Request, w http.ResponseWriter, code errorz.ErrCode) {
 	var errz *errorz.Error
 	if !errors.As(err, &errz) {
-		errz = t.errorResolver(err)
+		if code == errorz.Internal {
+			errz = t.errorResolver(err)
+		} else {
+			errz = errorz.Wrap(err, code, err.Error())
+		}
 	}
 	errz.Path = req.RequestURI
 
@@ -0,0 +1,261 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package httprpc_test
+
+import (
+	"bytes"
+	"context"
+	"errors"
+	"fmt"
+	"net"
+	"net/http"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/go-logr/logr"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/otel"
+	"go.opentelemetry.io/otel/propagation"
+	sdktrace "go.opentelemetry.io/otel/sdk/trace"
+	"go.opentelemetry.io/otel/trace"
+
+	json_codec "github.com/nanobus/nanobus/pkg/channel/codecs/json"
+	"github.com/nanobus/nanobus/pkg/errorz"
+	"github.com/nanobus/nanobus/pkg/handler"
+	"github.com/nanobus/nanobus/pkg/spec"
+	"github.com/nanobus/nanobus/pkg/transport"
+	"github.com/nanobus/nanobus/pkg/transport/httprpc"
+)
+
+func TestMaxBodySize(t *testing.T) {
+	echo := func(ctx context.Context, h handler.Handler, id string, input interface{}, authorization transport.Authorization) (interface{}, error) {
+		return input, nil
+	}
+	errorResolver := func(err error) *errorz.Error {
+		return errorz.Wrap(err, errorz.Internal, err.Error())
+	}
+
+	address := freeAddress(t)
+	server, err := httprpc.New(logr.Discard(), address, spec.Namespaces{}, echo, errorResolver,
+		httprpc.WithCodecs(json_codec.New()),
+		httprpc.WithMaxBodySize(64))
+	require.NoError(t, err)
+
+	done := make(chan error, 1)
+	go func() { done <- server.Listen() }()
+	defer func() {
+		require.NoError(t, server.Close())
+		require.NoError(t, <-done)
+	}()
+
+	url := "http://" + address + "/greeter/greeter::hello"
+	post := func(body string) *http.Response {
+		resp, err := http.Post(url, "application/json", bytes.NewBufferString(body))
+		require.NoError(t, err)
+		return resp
+	}
+
+	waitForListener(t, address)
+
+	resp := post(`{"name":"World"}`)
+	resp.Body.Close()
+	assert.Equal(t, http.StatusOK, resp.StatusCode)
+
+	resp = post(`{"name":"` + strings.Repeat("x", 64) + `"}`)
+	resp.Body.Close()
+	assert.Equal(t, http.StatusRequestEntityTooLarge, resp.StatusCode)
+}
+
+func TestErrorStatus(t *testing.T) {
+	invoker := func(ctx context.Context, h handler.Handler, id string, input interface{}, authorization transport.Authorization) (interface{}, error) {
+		switch h.Operation {
+		case "badInput":
+			return nil, fmt.Errorf("%w: name is required", transport.ErrBadInput)
+		case "missing":
+			return nil, errorz.New(errorz.Unimplemented, h.String()+" is not implemented")
+		case "failed":
+			return nil, errors.New("boom")
+		}
+		return input, nil
+	}
+	errorResolver := func(err error) *errorz.Error {
+		return errorz.Wrap(err, errorz.Internal, err.Error())
+	}
+
+	address := freeAddress(t)
+	server, err := httprpc.New(logr.Discard(), address, spec.Namespaces{}, invoker, errorResolver,
+		httprpc.WithCodecs(json_codec.New()))
+	require.NoError(t, err)
+
+	done := make(chan error, 1)
+	go func() { done <- server.Listen() }()
+	defer func() {
+		require.NoError(t, server.Close())
+		require.NoError(t, <-done)
+	}()
+	waitForListener(t, address)
+
+	tests := map[string]struct {
+		path   string
+		body   string
+		status int
+	}{
+		"ok":                {path: "/greeter/greeter::hello", body: `{}`, status: http.StatusOK},
+		"malformed handler": {path: "/greeter/hello", body: `{}`, status: http.StatusBadRequest},
+		"malformed body":    {path: "/greeter/greeter::hello", body: `{`, status: http.StatusBadRequest},
+		"bad input":         {path: "/greeter/greeter::badInput", body: `{}`, status: http.StatusBadRequest},
+		"unknown operation": {path: "/greeter/greeter::missing", body: `{}`, status: http.StatusNotImplemented},
+		"operation failure": {path: "/greeter/greeter::failed", body: `{}`, status: http.StatusInternalServerError},
+	}
+
+	for name, tt := range tests {
+		t.Run(name, func(t *testing.T) {
+			resp, err := http.Post("http://"+address+tt.path, "application/json", bytes.NewBufferString(tt.body))
+			require.NoError(t, err)
+			resp.Body.Close()
+			assert.Equal(t, tt.status, resp.StatusCode)
+		})
+	}
+}
+
+func TestCloseDrainsInFlightRequests(t *testing.T) {
+	started := make(chan struct{})
+	release := make(chan struct{})
+	invoker := func(ctx context.Context, h handler.Handler, id string, input interface{}, authorization transport.Authorization) (interface{}, error) {
+		close(started)
+		<-release
+		return input, nil
+	}
+	errorResolver := func(err error) *errorz.Error {
+		return errorz.Wrap(err, errorz.Internal, err.Error())
+	}
+
+	address := freeAddress(t)
+	server, err := httprpc.New(logr.Discard(), address, spec.Namespaces{}, invoker, errorResolver,
+		httprpc.WithCodecs(json_codec.New()),
+		httprpc.WithDrainTimeout(5*time.Second))
+	require.NoError(t, err)
+
+	done := make(chan error, 1)
+	go func() { done <- server.Listen() }()
+	waitForListener(t, address)
+
+	type result struct {
+		status int
+		err    error
+	}
+	results := make(chan result, 1)
+	go func() {
+		resp, err := http.Post("http://"+address+"/greeter/greeter::hello", "application/json", bytes.NewBufferString(`{}`))
+		if err != nil {
+			results <- result{err: err}
+			return
+		}
+		resp.Body.Close()
+		results <- result{status: resp.StatusCode}
+	}()
+	<-started
+
+	closed := make(chan error, 1)
+	go func() { closed <- server.Close() }()
+
+	// Shutting down stops accepting new connections first.
+	require.Eventually(t, func() bool {
+		conn, err := net.Dial("tcp", address)
+		if err == nil {
+			conn.Close()
+		}
+		return err != nil
+	}, 5*time.Second, 10*time.Millisecond)
+	close(release)
+
+	res := <-results
+	require.NoError(t, res.err)
+	assert.Equal(t, http.StatusOK, res.status)
+	require.NoError(t, <-closed)
+	require.NoError(t, <-done)
+}
+
+func TestTracePropagation(t *testing.T) {
+	prevPropagator := otel.GetTextMapPropagator()
+	prevProvider := otel.GetTracerProvider()
+	otel.SetTextMapPropagator(propagation.TraceContext{})
+	otel.SetTracerProvider(sdktrace.NewTracerProvider())
+	defer func() {
+		otel.SetTextMapPropagator(prevPropagator)
+		otel.SetTracerProvider(prevProvider)
+	}()
+
+	const (
+		traceID  = "4bf92f3577b34da6a3ce929d0e0e4736"
+		parentID = "00f067aa0ba902b7"
+	)
+
+	spans := make(chan trace.Span, 1)
+	invoker := func(ctx context.Context, h handler.Handler, id string, input interface{}, authorization transport.Authorization) (interface{}, error) {
+		spans <- trace.SpanFromContext(ctx)
+		return input, nil
+	}
+	errorResolver := func(err error) *errorz.Error {
+		return errorz.Wrap(err, errorz.Internal, err.Error())
+	}
+
+	address := freeAddress(t)
+	server, err := httprpc.New(logr.Discard(), address, spec.Namespaces{}, invoker, errorResolver,
+		httprpc.WithCodecs(json_codec.New()))
+	require.NoError(t, err)
+
+	done := make(chan error, 1)
+	go func() { done <- server.Listen() }()
+	defer func() {
+		require.NoError(t, server.Close())
+		require.NoError(t, <-done)
+	}()
+	waitForListener(t, address)
+
+	req, err := http.NewRequest(http.MethodPost, "http://"+address+"/greeter/greeter::hello", bytes.NewBufferString(`{}`))
+	require.NoError(t, err)
+	req.Header.Set("Content-Type", "application/json")
+	req.Header.Set("traceparent", "00-"+traceID+"-"+parentID+"-01")
+	resp, err := http.DefaultClient.Do(req)
+	require.NoError(t, err)
+	resp.Body.Close()
+	require.Equal(t, http.StatusOK, resp.StatusCode)
+
+	span := <-spans
+	assert.Equal(t, traceID, span.SpanContext().TraceID().String())
+	readOnly, ok := span.(sdktrace.ReadOnlySpan)
+	require.True(t, ok)
+	assert.Equal(t, parentID, readOnly.Parent().SpanID().String())
+	assert.True(t, readOnly.Parent().IsRemote())
+}
+
+func waitForListener(t *testing.T, address string) {
+	t.Helper()
+
+	require.Eventually(t, func() bool {
+		conn, err := net.Dial("tcp", address)
+		if err == nil {
+			conn.Close()
+		}
+		return err == nil
+	}, 5*time.Second, 10*time.Millisecond)
+}
+
+func freeAddress(t *testing.T) string {
+	t.Helper()
+
+	ln, err := net.Listen("tcp", "127.0.0.1:0")
+	require.NoError(t, err)
+	defer ln.Close()
+
+	return ln.Addr().String()
+}
@@ -0,0 +1,65 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package transport
+
+import (
+	"context"
+	"fmt"
+	"net"
+	"os"
+	"strings"
+)
+
+// UnixPrefix marks a listening address as a Unix domain socket path
+// (e.g. `unix:/var/run/nanobus.sock`).
+const UnixPrefix = "unix:"
+
+// Listen announces on address. Addresses starting with `unix:` listen on
+// a Unix domain socket, which is removed again when the listener is
+// closed. All other addresses are TCP host:port pairs.
+func Listen(address string) (net.Listener, error) {
+	return ListenConfig(net.ListenConfig{}, address)
+}
+
+// ListenConfig is like Listen but uses lc to create the listener, for
+// example to change the TCP keep-alive period of accepted connections.
+func ListenConfig(lc net.ListenConfig, address string) (net.Listener, error) {
+	ctx := context.Background()
+	if !strings.HasPrefix(address, UnixPrefix) {
+		return lc.Listen(ctx, "tcp", address)
+	}
+
+	path := strings.TrimPrefix(address, UnixPrefix)
+	if err := removeStaleSocket(path); err != nil {
+		return nil, err
+	}
+
+	return lc.Listen(ctx, "unix", path)
+}
+
+// removeStaleSocket deletes a socket file left behind by a process that
+// did not shut down cleanly. Sockets that still accept connections are
+// left alone so that listening fails with "address in use".
+func removeStaleSocket(path string) error {
+	info, err := os.Stat(path)
+	if err != nil || info.Mode()&os.ModeSocket == 0 {
+		return nil
+	}
+
+	if conn, err := net.Dial("unix", path); err == nil {
+		conn.Close()
+		return nil
+	}
+
+	if err := os.Remove(path); err != nil {
+		return fmt.Errorf("could not remove stale socket %s: %w", path, err)
+	}
+
+	return nil
+}
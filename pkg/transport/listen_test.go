@@ -0,0 +1,72 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package transport_test
+
+import (
+	"net"
+	"os"
+	"path/filepath"
+	goruntime "runtime"
+	"testing"
+
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+
+	"github.com/nanobus/nanobus/pkg/transport"
+)
+
+func TestListenTCP(t *testing.T) {
+	ln, err := transport.Listen("127.0.0.1:0")
+	require.NoError(t, err)
+	defer ln.Close()
+
+	assert.Equal(t, "tcp", ln.Addr().Network())
+}
+
+func TestListenUnix(t *testing.T) {
+	if goruntime.GOOS == "windows" {
+		t.Skip("Unix domain sockets are not supported")
+	}
+
+	path := filepath.Join(t.TempDir(), "nanobus.sock")
+
+	ln, err := transport.Listen("unix:" + path)
+	require.NoError(t, err)
+	assert.Equal(t, "unix", ln.Addr().Network())
+
+	// A socket that is still being served is not taken over.
+	_, err = transport.Listen("unix:" + path)
+	assert.Error(t, err)
+
+	conn, err := net.Dial("unix", path)
+	require.NoError(t, err)
+	conn.Close()
+
+	require.NoError(t, ln.Close())
+	_, err = os.Stat(path)
+	assert.True(t, os.IsNotExist(err), "socket file should be removed on close")
+}
+
+func TestListenUnixStaleSocket(t *testing.T) {
+	if goruntime.GOOS == "windows" {
+		t.Skip("Unix domain sockets are not supported")
+	}
+
+	path := filepath.Join(t.TempDir(), "nanobus.sock")
+
+	// Simulate a crashed process by leaving the socket file behind.
+	stale, err := net.Listen("unix", path)
+	require.NoError(t, err)
+	stale.(*net.UnixListener).SetUnlinkOnClose(false)
+	require.NoError(t, stale.Close())
+
+	ln, err := transport.Listen("unix:" + path)
+	require.NoError(t, err)
+	require.NoError(t, ln.Close())
+}
@@ -0,0 +1,43 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package nats
+
+import (
+	"net/http"
+
+	"github.com/nats-io/nats.go"
+)
+
+// headerCarrier adapts nats.Header to propagation.TextMapCarrier.
+// NATS headers are case-sensitive, so keys are read exactly as stored
+// (e.g. `traceparent`) rather than canonicalized like HTTP headers.
+// Canonical keys written by HTTP-style clients are still accepted.
+type headerCarrier nats.Header
+
+func (c headerCarrier) Get(key string) string {
+	if v := c[key]; len(v) > 0 {
+		return v[0]
+	}
+	if v := c[http.CanonicalHeaderKey(key)]; len(v) > 0 {
+		return v[0]
+	}
+	return ""
+}
+
+func (c headerCarrier) Set(key, value string) {
+	c[key] = []string{value}
+}
+
+func (c headerCarrier) Keys() []string {
+	keys := make([]string, 0, len(c))
+	for k := range c {
+		keys = append(keys, k)
+	}
+	return keys
+}
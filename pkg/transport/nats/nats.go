@@ -14,10 +14,11 @@ import (
 	"fmt"
 	"net/http"
 	"strconv"
+	"time"
 
 	"github.com/go-logr/logr"
 	"github.com/nats-io/nats.go"
-	"go.uber.org/multierr"
+	"go.opentelemetry.io/otel"
 
 	"github.com/nanobus/nanobus/pkg/channel"
 	"github.com/nanobus/nanobus/pkg/config"
@@ -35,17 +36,18 @@ type NATS struct {
 	ctx           context.Context
 	cancel        context.CancelFunc
 	nc            *nats.Conn
+	closed        chan struct{}
 	namespaces    spec.Namespaces
 	invoker       transport.Invoker
 	errorResolver errorz.Resolver
 	codecs        map[string]channel.Codec
 	filters       []filter.Filter
-	subs          []*nats.Subscription
 }
 
 type optionsHolder struct {
-	codecs  []channel.Codec
-	filters []filter.Filter
+	codecs       []channel.Codec
+	filters      []filter.Filter
+	drainTimeout time.Duration
 }
 
 var (
@@ -66,8 +68,16 @@ func WithFilters(filters ...filter.Filter) Option {
 	}
 }
 
+// WithDrainTimeout sets how long Close waits for in-flight messages.
+func WithDrainTimeout(drainTimeout time.Duration) Option {
+	return func(opts *optionsHolder) {
+		opts.drainTimeout = drainTimeout
+	}
+}
+
 type Configuration struct {
-	Address string `mapstructure:"address" validate:"required"`
+	Address      string        `mapstructure:"address" validate:"required"`
+	DrainTimeout time.Duration `mapstructure:"drainTimeout"`
 }
 
 func Load() (string, transport.Loader) {
@@ -93,18 +103,23 @@ func Loader(ctx context.Context, with interface{}, resolver resolve.ResolveAs) (
 		return nil, err
 	}
 
-	var c Configuration
+	c := Configuration{
+		DrainTimeout: transport.DefaultDrainTimeout,
+	}
 	if err := config.Decode(with, &c); err != nil {
 		return nil, err
 	}
 
 	return New(log, c.Address, namespaces, transportInvoker, errorResolver,
 		WithFilters(filters...),
-		WithCodecs(jsoncodec, msgpackcodec))
+		WithCodecs(jsoncodec, msgpackcodec),
+		WithDrainTimeout(c.DrainTimeout))
 }
 
 func New(log logr.Logger, address string, namespaces spec.Namespaces, invoker transport.Invoker, errorResolver errorz.Resolver, options ...Option) (transport.Transport, error) {
-	var opts optionsHolder
+	opts := optionsHolder{
+		drainTimeout: transport.DefaultDrainTimeout,
+	}
 
 	for _, opt := range options {
 		opt(&opts)
@@ -116,7 +131,10 @@ func New(log logr.Logger, address string, namespaces spec.Namespaces, invoker tr
 	}
 
 	ctx, cancel := context.WithCancel(context.Background())
-	nc, err := nats.Connect(address)
+	closed := make(chan struct{})
+	nc, err := nats.Connect(address,
+		nats.DrainTimeout(opts.drainTimeout),
+		nats.ClosedHandler(func(*nats.Conn) { close(closed) }))
 	if err != nil {
 		cancel()
 		return nil, err
@@ -129,6 +147,7 @@ func New(log logr.Logger, address string, namespaces spec.Namespaces, invoker tr
 		ctx:           ctx,
 		cancel:        cancel,
 		nc:            nc,
+		closed:        closed,
 		namespaces:    namespaces,
 		invoker:       invoker,
 		errorResolver: errorResolver,
@@ -155,22 +174,28 @@ func (t *NATS) Listen() error {
 		}
 		subs = append(subs, sub)
 	}
-	t.subs = subs
 
 	<-t.ctx.Done()
 
 	return nil
 }
 
-func (t *NATS) Close() (merr error) {
+// Close drains the connection: subscriptions stop receiving new
+// messages, messages already received are handled and their replies
+// flushed, then the connection is closed. Draining is bounded by the
+// drain timeout. Closing a connection that is already closed succeeds.
+func (t *NATS) Close() error {
 	defer t.cancel()
 
-	for _, sub := range t.subs {
-		merr = multierr.Append(merr, sub.Unsubscribe())
-		merr = multierr.Append(merr, sub.Drain())
+	if err := t.nc.Drain(); err != nil {
+		if errors.Is(err, nats.ErrConnectionClosed) {
+			return nil
+		}
+		return err
 	}
+	<-t.closed
 
-	return merr
+	return nil
 }
 
 func (t *NATS) handler(m *nats.Msg) {
@@ -203,16 +228,18 @@ func (t *NATS) handler(m *nats.Msg) {
 
 	var h handler.Handler
 	if err := h.FromString(operation); err != nil {
-		t.handleError(err, codec, m, http.StatusInternalServerError)
+		t.handleError(err, codec, m, errorz.InvalidArgument)
 		return
 	}
 
-	ctx := context.Background()
+	// Continue the caller's trace from W3C trace context headers.
+	ctx := otel.GetTextMapPropagator().Extract(context.Background(),
+		headerCarrier(m.Header))
 
 	for _, filter := range t.filters {
 		var err error
 		if ctx, err = filter(ctx, m.Header); err != nil {
-			t.handleError(err, codec, m, http.StatusInternalServerError)
+			t.handleError(err, codec, m, errorz.Internal)
 			return
 		}
 	}
@@ -222,7 +249,7 @@ func (t *NATS) handler(m *nats.Msg) {
 	var input interface{}
 	if len(requestBytes) > 0 {
 		if err := codec.Decode(requestBytes, &input); err != nil {
-			t.handleError(err, codec, m, http.StatusInternalServerError)
+			t.handleError(err, codec, m, errorz.InvalidArgument)
 			return
 		}
 	} else {
@@ -231,9 +258,9 @@ func (t *NATS) handler(m *nats.Msg) {
 
 	response, err := t.invoker(ctx, h, id, input, transport.PerformAuthorization)
 	if err != nil {
-		code := http.StatusInternalServerError
+		code := errorz.Internal
 		if errors.Is(err, transport.ErrBadInput) {
-			code = http.StatusBadRequest
+			code = errorz.InvalidArgument
 		}
 		t.handleError(err, codec, m, code)
 		return
@@ -247,7 +274,7 @@ func (t *NATS) handler(m *nats.Msg) {
 	header.Set("Content-Type", codec.ContentType())
 	reply.Data, err = codec.Encode(response)
 	if err != nil {
-		t.handleError(err, codec, m, http.StatusInternalServerError)
+		t.handleError(err, codec, m, errorz.Internal)
 		return
 	}
 	if err := m.RespondMsg(&reply); err != nil {
@@ -255,8 +282,18 @@ func (t *NATS) handler(m *nats.Msg) {
 	}
 }
 
-func (t *NATS) handleError(err error, codec channel.Codec, m *nats.Msg, status int) {
-	errz := t.errorResolver(err)
+// handleError replies with err. Errors that are not already an
+// *errorz.Error are reported with code, or passed to the error resolver
+// when code is Internal.
+func (t *NATS) handleError(err error, codec channel.Codec, m *nats.Msg, code errorz.ErrCode) {
+	var errz *errorz.Error
+	if !errors.As(err, &errz) {
+		if code == errorz.Internal {
+			errz = t.errorResolver(err)
+		} else {
+			errz = errorz.Wrap(err, code, err.Error())
+		}
+	}
 	errz.Path = m.Subject
 
 	header := make(nats.Header)
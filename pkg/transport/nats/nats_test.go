@@ -0,0 +1,88 @@
+/*
+ * Copyright 2022 The NanoBus Authors.
+ *
+ * This Source Code Form is subject to the terms of the Mozilla Public
+ * License, v. 2.0. If a copy of the MPL was not distributed with this
+ * file, You can obtain one at https://mozilla.org/MPL/2.0/.
+ */
+
+package nats
+
+import (
+	"context"
+	"testing"
+
+	"github.com/go-logr/logr"
+	"github.com/nats-io/nats.go"
+	"github.com/stretchr/testify/assert"
+	"github.com/stretchr/testify/require"
+	"go.opentelemetry.io/otel"
+	"go.opentelemetry.io/otel/propagation"
+	"go.opentelemetry.io/otel/trace"
+
+	"github.com/nanobus/nanobus/pkg/channel"
+	json_codec "github.com/nanobus/nanobus/pkg/channel/codecs/json"
+	"github.com/nanobus/nanobus/pkg/errorz"
+	"github.com/nanobus/nanobus/pkg/handler"
+	"github.com/nanobus/nanobus/pkg/transport"
+)
+
+const (
+	traceID     = "4bf92f3577b34da6a3ce929d0e0e4736"
+	parentID    = "00f067aa0ba902b7"
+	traceparent = "00-" + traceID + "-" + parentID + "-01"
+)
+
+func TestHandlerContinuesTrace(t *testing.T) {
+	prev := otel.GetTextMapPropagator()
+	otel.SetTextMapPropagator(propagation.TraceContext{})
+	defer otel.SetTextMapPropagator(prev)
+
+	tests := map[string]string{
+		"lowercase": "traceparent",
+		"canonical": "Traceparent",
+	}
+
+	for name, key := range tests {
+		t.Run(name, func(t *testing.T) {
+			var spanContext trace.SpanContext
+			codec := json_codec.New()
+			n := &NATS{
+				log: logr.Discard(),
+				invoker: func(ctx context.Context, h handler.Handler, id string, input interface{}, authorization transport.Authorization) (interface{}, error) {
+					spanContext = trace.SpanContextFromContext(ctx)
+					return input, nil
+				},
+				errorResolver: func(err error) *errorz.Error {
+					return errorz.Wrap(err, errorz.Internal, err.Error())
+				},
+				codecs: map[string]channel.Codec{codec.ContentType(): codec},
+			}
+
+			n.handler(&nats.Msg{
+				Subject: "greeter.hello",
+				Header: nats.Header{
+					"Operation": []string{"greeter::hello"},
+					key:         []string{traceparent},
+				},
+				Data: []byte(`{}`),
+			})
+
+			require.True(t, spanContext.IsValid())
+			assert.Equal(t, traceID, spanContext.TraceID().String())
+			assert.Equal(t, parentID, spanContext.SpanID().String())
+			assert.True(t, spanContext.IsRemote())
+		})
+	}
+}
+
+func TestHeaderCarrier(t *testing.T) {
+	header := nats.Header{}
+	carrier := headerCarrier(header)
+	carrier.Set("traceparent", traceparent)
+
+	assert.Equal(t, []string{traceparent}, header["traceparent"])
+	assert.Equal(t, traceparent, carrier.Get("traceparent"))
+	assert.Equal(t, []string{"traceparent"}, carrier.Keys())
+	assert.Equal(t, "", carrier.Get("tracestate"))
+}
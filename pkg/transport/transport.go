@@ -11,6 +11,10 @@ package transport
 import (
 	"context"
 	"errors"
+	"sync"
+	"time"
+
+	"go.uber.org/multierr"
 
 	"github.com/nanobus/nanobus/pkg/handler"
 	"github.com/nanobus/nanobus/pkg/resolve"
@@ -18,6 +22,10 @@ import (
 
 var ErrBadInput = errors.New("input was malformed")
 
+// DefaultDrainTimeout bounds how long a transport waits for in-flight
+// requests to complete when it is closed.
+const DefaultDrainTimeout = 10 * time.Second
+
 type (
 	NamedLoader func() (string, Loader)
 	Loader      func(ctx context.Context, with interface{}, resolver resolve.ResolveAs) (Transport, error)
@@ -45,3 +53,22 @@ func (r Registry) Register(loaders ...NamedLoader) {
 		r[name] = loader
 	}
 }
+
+// CloseAll closes transports concurrently. Their drain timeouts start
+// together, so shutdown takes as long as the slowest drain rather than
+// the sum of all of them.
+func CloseAll(transports ...Transport) error {
+	errs := make([]error, len(transports))
+	var wg sync.WaitGroup
+	wg.Add(len(transports))
+	for i, t := range transports {
+		i, t := i, t
+		go func() {
+			defer wg.Done()
+			errs[i] = t.Close()
+		}()
+	}
+	wg.Wait()
+
+	return multierr.Combine(errs...)
+}
@@ -10,8 +10,10 @@ package transport_test
 
 import (
 	"context"
+	"errors"
 	"fmt"
 	"testing"
+	"time"
 
 	"github.com/stretchr/testify/assert"
 
@@ -33,3 +35,24 @@ func TestRegistry(t *testing.T) {
 
 	assert.Equal(t, fmt.Sprintf("%v", transport.Loader(loader)), fmt.Sprintf("%p", r["test"]))
 }
+
+type closeFunc func() error
+
+func (f closeFunc) Listen() error { return nil }
+func (f closeFunc) Close() error  { return f() }
+
+func TestCloseAll(t *testing.T) {
+	drain := func(err error) transport.Transport {
+		return closeFunc(func() error {
+			time.Sleep(200 * time.Millisecond)
+			return err
+		})
+	}
+
+	start := time.Now()
+	err := transport.CloseAll(drain(nil), drain(errors.New("boom")), drain(nil))
+	elapsed := time.Since(start)
+
+	assert.EqualError(t, err, "boom")
+	assert.Less(t, elapsed, 400*time.Millisecond, "transports were not drained concurrently")
+}